@@ -0,0 +1,140 @@
+//! bytes
+//!
+//! This module contains a compact binary encoding of `f64` complex numbers: each
+//! number takes 16 bytes, the real part followed by the imaginary part, both as
+//! little-endian IEEE 754 doubles.
+//!
+//! # Examples
+//!
+//! ```
+//! use xcomplex::bytes::{decode, encode};
+//! use xcomplex::number::Complex;
+//!
+//! let data = vec![Complex::new(1.0, 2.0), Complex::new(-0.5, 1e-300)];
+//! let bytes = encode(&data);
+//! assert_eq!(bytes.len(), 32);
+//! assert_eq!(decode(&bytes), Some(data));
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::number::Complex;
+
+/// The number of bytes used to encode one complex number.
+pub const ENCODED_LEN: usize = 16;
+
+impl Complex<f64> {
+    /// Append the little-endian encoding of the complex number to `out`.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let mut out = Vec::new();
+    /// Complex::new(1.0, -2.0).write_le_bytes(&mut out);
+    /// assert_eq!(out.len(), 16);
+    /// assert_eq!(out[..8], 1.0_f64.to_le_bytes());
+    /// assert_eq!(out[8..], (-2.0_f64).to_le_bytes());
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn write_le_bytes(&self, out: &mut Vec<u8>) {
+        out.extend_from_slice(&self.re.to_le_bytes());
+        out.extend_from_slice(&self.im.to_le_bytes());
+    }
+
+    /// Decode a complex number from exactly 16 little-endian bytes.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(3.5, f64::NEG_INFINITY);
+    /// let mut bytes = Vec::new();
+    /// c.write_le_bytes(&mut bytes);
+    /// assert_eq!(Complex::from_le_bytes(&bytes), Some(c));
+    /// assert_eq!(Complex::from_le_bytes(&bytes[1..]), None);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// The bit patterns are restored exactly, including signed zeros and NaN
+    /// payloads. Any length other than 16 returns `None`.
+    pub fn from_le_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != ENCODED_LEN {
+            return None;
+        }
+        let (re, im) = bytes.split_at(ENCODED_LEN / 2);
+        Some(Self::new(
+            f64::from_le_bytes(re.try_into().ok()?),
+            f64::from_le_bytes(im.try_into().ok()?),
+        ))
+    }
+}
+
+/// Encode a slice of complex numbers into consecutive 16-byte records.
+/// # Examples
+/// ```
+/// use xcomplex::bytes::encode;
+/// use xcomplex::number::Complex;
+/// let data = [Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)];
+/// let bytes = encode(&data);
+/// assert_eq!(bytes.len(), 16 * data.len());
+/// assert_eq!(bytes[16..24], 3.0_f64.to_le_bytes());
+/// assert!(encode(&[]).is_empty());
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+pub fn encode(data: &[Complex<f64>]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len() * ENCODED_LEN);
+    for z in data {
+        z.write_le_bytes(&mut out);
+    }
+    out
+}
+
+/// Decode consecutive 16-byte records into complex numbers.
+/// # Examples
+/// ```
+/// use xcomplex::bytes::{decode, encode};
+/// use xcomplex::number::Complex;
+/// let data: Vec<Complex> = (0..100)
+///     .map(|k| Complex::new(k as f64 * 0.1, -(k as f64).sqrt()))
+///     .collect();
+/// let bytes = encode(&data);
+/// let back = decode(&bytes).unwrap();
+/// assert!(back.iter().zip(data.iter()).all(|(a, b)| a.bit_eq(b)));
+/// assert_eq!(decode(&bytes[..31]), None);
+/// assert_eq!(decode(&[]), Some(Vec::new()));
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// This function returns `None` if the length is not a multiple of 16.
+pub fn decode(bytes: &[u8]) -> Option<Vec<Complex<f64>>> {
+    let records = bytes.chunks_exact(ENCODED_LEN);
+    if !records.remainder().is_empty() {
+        return None;
+    }
+    records.map(Complex::from_le_bytes).collect()
+}
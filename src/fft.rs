@@ -0,0 +1,380 @@
+//! fast fourier transform
+//!
+//! This module contains discrete Fourier transforms over slices of complex numbers:
+//! in-place fast transforms for power of two lengths, and direct transforms for
+//! any length.
+//! The forward transform uses the kernel `exp(-2 PI i k n / N)` and the inverse
+//! transform is scaled by `1 / N`.
+//! Convolution and correlation are built on top of the fast transform.
+//!
+//! # Examples
+//!
+//! ```
+//! use xcomplex::fft::{fft, ifft};
+//! use xcomplex::number::Complex;
+//!
+//! let mut data = vec![Complex::new(1.0, 0.0); 4];
+//! fft(&mut data);
+//! assert_eq!(data[0], Complex::new(4.0, 0.0));
+//! ifft(&mut data);
+//! assert!(data[3].approx_eq(&Complex::new(1.0, 0.0), 1e-12));
+//! ```
+//!
+//! # References
+//!
+//! * [Cooley-Tukey FFT algorithm](https://en.wikipedia.org/wiki/Cooley%E2%80%93Tukey_FFT_algorithm)
+//! * [Convolution theorem](https://en.wikipedia.org/wiki/Convolution_theorem)
+//! * [Cross-correlation](https://en.wikipedia.org/wiki/Cross-correlation)
+//! * [Window function](https://en.wikipedia.org/wiki/Window_function)
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::float::Float;
+use crate::number::Complex;
+
+const DIRECT_CONVOLUTION_LIMIT: usize = 32;
+
+/// Compute the discrete Fourier transform of the data in place.
+/// # Examples
+/// ```
+/// use xcomplex::fft::fft;
+/// use xcomplex::number::Complex;
+/// let mut data = vec![Complex::new(2.0, 1.0); 8];
+/// fft(&mut data);
+/// assert!(data[0].approx_eq(&Complex::new(16.0, 8.0), 1e-12));
+/// assert!(data[1..].iter().all(|z| z.norm() < 1e-12));
+/// ```
+/// ```should_panic
+/// use xcomplex::fft::fft;
+/// use xcomplex::number::Complex;
+/// let mut data = vec![Complex::new(1.0, 0.0); 3];
+/// fft(&mut data);
+/// ```
+/// # Panics
+/// This function panics if the length of the data is not a power of two.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// This function uses the iterative radix-2 Cooley-Tukey algorithm.
+pub fn fft<T: Float>(data: &mut [Complex<T>]) {
+    transform(data, -T::ONE);
+}
+
+/// Compute the inverse discrete Fourier transform of the data in place.
+/// # Examples
+/// ```
+/// use xcomplex::fft::{fft, ifft};
+/// use xcomplex::number::Complex;
+/// let signal: Vec<Complex> = (0..8)
+///     .map(|k| Complex::new(k as f64, (k * k) as f64 / 4.0))
+///     .collect();
+/// let mut data = signal.clone();
+/// fft(&mut data);
+/// ifft(&mut data);
+/// for (a, b) in data.iter().zip(signal.iter()) {
+///     assert!(a.approx_eq(b, 1e-12));
+/// }
+/// ```
+/// # Panics
+/// This function panics if the length of the data is not a power of two.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+pub fn ifft<T: Float>(data: &mut [Complex<T>]) {
+    transform(data, T::ONE);
+    let n = T::from_f64(data.len() as f64);
+    for z in data.iter_mut() {
+        *z = *z / n;
+    }
+}
+
+/// Compute the discrete Fourier transform of a sequence of any length.
+/// # Examples
+/// ```
+/// use xcomplex::fft::{dft, fft};
+/// use xcomplex::number::Complex;
+/// let signal: Vec<Complex> = (0..8).map(|k| Complex::new(k as f64, 1.0)).collect();
+/// let mut fast = signal.clone();
+/// fft(&mut fast);
+/// for (a, b) in dft(&signal).iter().zip(fast.iter()) {
+///     assert!(a.approx_eq(b, 1e-12));
+/// }
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// This function evaluates the definition directly in `O(n^2)` time.
+pub fn dft<T: Float>(input: &[Complex<T>]) -> Vec<Complex<T>> {
+    direct(input, -T::ONE)
+}
+
+/// Compute the inverse discrete Fourier transform of a sequence of any length.
+/// # Examples
+/// ```
+/// use xcomplex::fft::{dft, idft};
+/// use xcomplex::number::Complex;
+/// let signal = [
+///     Complex::new(1.0, 0.0),
+///     Complex::new(2.0, -1.0),
+///     Complex::new(0.0, 3.0),
+///     Complex::new(-4.0, 0.5),
+///     Complex::new(2.5, 2.5),
+/// ];
+/// let recovered = idft(&dft(&signal));
+/// assert_eq!(recovered.len(), 5);
+/// for (a, b) in recovered.iter().zip(signal.iter()) {
+///     assert!(a.approx_eq(b, 1e-12));
+/// }
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+pub fn idft<T: Float>(input: &[Complex<T>]) -> Vec<Complex<T>> {
+    let n = T::from_f64(input.len() as f64);
+    direct(input, T::ONE).into_iter().map(|z| z / n).collect()
+}
+
+/// Return the linear convolution of two sequences.
+/// # Examples
+/// ```
+/// use xcomplex::fft::convolve;
+/// use xcomplex::number::Complex;
+/// let a = [Complex::new(1.0, 1.0), Complex::new(2.0, 0.0)];
+/// let b = [Complex::new(1.0, 0.0), Complex::new(0.0, -1.0), Complex::new(3.0, 0.0)];
+/// let expected = [
+///     Complex::new(1.0, 1.0),
+///     Complex::new(3.0, -1.0),
+///     Complex::new(3.0, 1.0),
+///     Complex::new(6.0, 0.0),
+/// ];
+/// assert_eq!(convolve(&a, &b), expected);
+/// assert!(convolve(&a, &[]).is_empty());
+/// // long inputs go through the FFT and agree with the direct sum
+/// let x: Vec<Complex> = (0..100).map(|k| Complex::new(k as f64, 1.0 / (k + 1) as f64)).collect();
+/// let y: Vec<Complex> = (0..70).map(|k| Complex::new((k % 7) as f64, -(k as f64))).collect();
+/// let z = convolve(&x, &y);
+/// assert_eq!(z.len(), 169);
+/// for n in 0..z.len() {
+///     let mut sum = Complex::new(0.0, 0.0);
+///     for k in 0..x.len() {
+///         if n >= k && n - k < y.len() {
+///             sum += x[k] * y[n - k];
+///         }
+///     }
+///     assert!(z[n].approx_eq(&sum, 1e-8 * sum.norm().max(1.0)));
+/// }
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// The result has `a.len() + b.len() - 1` elements, or none if either input is
+/// empty. When the shorter input has at most 32 elements the sum is evaluated
+/// directly. Otherwise both inputs are zero-padded to a power of two and
+/// multiplied in the frequency domain.
+pub fn convolve<T: Float>(a: &[Complex<T>], b: &[Complex<T>]) -> Vec<Complex<T>> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let len = a.len() + b.len() - 1;
+    if a.len().min(b.len()) <= DIRECT_CONVOLUTION_LIMIT {
+        let mut out = vec![Complex::ZERO; len];
+        for (i, x) in a.iter().enumerate() {
+            for (j, y) in b.iter().enumerate() {
+                out[i + j] += *x * *y;
+            }
+        }
+        return out;
+    }
+    let n = len.next_power_of_two();
+    let mut fa = a.to_vec();
+    fa.resize(n, Complex::ZERO);
+    let mut fb = b.to_vec();
+    fb.resize(n, Complex::ZERO);
+    fft(&mut fa);
+    fft(&mut fb);
+    for (x, y) in fa.iter_mut().zip(fb.iter()) {
+        *x *= *y;
+    }
+    ifft(&mut fa);
+    fa.truncate(len);
+    fa
+}
+
+/// Return the cross-correlation `sum(a[n + k] * b[n].conj())` of two sequences for every lag `k`.
+/// # Examples
+/// ```
+/// use xcomplex::fft::correlate;
+/// use xcomplex::number::Complex;
+/// let a = [
+///     Complex::new(1.0, 2.0),
+///     Complex::new(-1.0, 0.5),
+///     Complex::new(3.0, -1.0),
+///     Complex::new(0.0, 1.0),
+/// ];
+/// let r = correlate(&a, &a);
+/// assert_eq!(r.len(), 7);
+/// // zero lag sits at index `b.len() - 1` and holds the energy of the signal
+/// let energy: f64 = a.iter().map(|z| z.norm_sqr()).sum();
+/// assert!(r[3].approx_eq(&Complex::new(energy, 0.0), 1e-12));
+/// assert!(r.iter().enumerate().all(|(k, z)| k == 3 || z.norm() < r[3].norm()));
+/// // the lag at which a shifted copy lines up is recovered from the peak
+/// let b = [Complex::new(0.0, 0.0), a[0], a[1], a[2], a[3]];
+/// let r = correlate(&b, &a);
+/// let peak = (0..r.len()).max_by(|&i, &j| r[i].norm().total_cmp(&r[j].norm())).unwrap();
+/// assert_eq!(peak as isize - (a.len() as isize - 1), 1);
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// This function convolves `a` with the reversed conjugate of `b`, so index `m`
+/// of the result holds lag `k = m - (b.len() - 1)`, running from
+/// `-(b.len() - 1)` to `a.len() - 1`.
+pub fn correlate<T: Float>(a: &[Complex<T>], b: &[Complex<T>]) -> Vec<Complex<T>> {
+    let kernel: Vec<Complex<T>> = b.iter().rev().map(|z| z.conj()).collect();
+    convolve(a, &kernel)
+}
+
+/// A window function applied to a signal before transforming it.
+/// # Examples
+/// ```
+/// use xcomplex::fft::{apply_window, Window};
+/// use xcomplex::number::Complex;
+/// let mut data = vec![Complex::new(1.0, 1.0); 4];
+/// apply_window(&mut data, Window::Rectangular);
+/// assert_eq!(data, vec![Complex::new(1.0, 1.0); 4]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Window {
+    /// Leave the samples unchanged.
+    Rectangular,
+    /// The Hann window `0.5 - 0.5 cos(2 PI n / (N - 1))`, which is zero at both ends.
+    Hann,
+    /// The Hamming window `0.54 - 0.46 cos(2 PI n / (N - 1))`.
+    Hamming,
+}
+
+/// Multiply every sample by the coefficient of a window function.
+/// # Examples
+/// ```
+/// use xcomplex::fft::{apply_window, Window};
+/// use xcomplex::number::Complex;
+/// let mut data = vec![Complex::new(2.0, -1.0); 9];
+/// apply_window(&mut data, Window::Hann);
+/// assert_eq!(data[0], Complex::new(0.0, 0.0));
+/// assert!(data[8].norm() < 1e-15);
+/// assert!(data[4].approx_eq(&Complex::new(2.0, -1.0), 1e-15));
+/// assert!(data[2].approx_eq(&Complex::new(1.0, -0.5), 1e-15));
+/// let mut data = vec![Complex::new(1.0, 0.0); 9];
+/// apply_window(&mut data, Window::Hamming);
+/// assert!(data[0].approx_eq(&Complex::new(0.08, 0.0), 1e-15));
+/// assert!(data[4].approx_eq(&Complex::new(1.0, 0.0), 1e-15));
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// The windows are symmetric over the length of the data. A single sample is
+/// always left unchanged.
+pub fn apply_window<T: Float>(data: &mut [Complex<T>], window: Window) {
+    let (a, b) = match window {
+        Window::Rectangular => return,
+        Window::Hann => (T::from_f64(0.5), T::from_f64(0.5)),
+        Window::Hamming => (T::from_f64(0.54), T::from_f64(0.46)),
+    };
+    if data.len() < 2 {
+        return;
+    }
+    let step = T::TWO * T::PI / T::from_f64((data.len() - 1) as f64);
+    for (n, z) in data.iter_mut().enumerate() {
+        *z = *z * (a - b * (step * T::from_f64(n as f64)).cos());
+    }
+}
+
+fn direct<T: Float>(input: &[Complex<T>], sign: T) -> Vec<Complex<T>> {
+    let n = input.len();
+    let step = sign * T::TWO * T::PI / T::from_f64(n as f64);
+    (0..n)
+        .map(|k| {
+            input
+                .iter()
+                .enumerate()
+                .map(|(j, x)| *x * Complex::cis(step * T::from_f64(((j * k) % n) as f64)))
+                .sum()
+        })
+        .collect()
+}
+
+fn transform<T: Float>(data: &mut [Complex<T>], sign: T) {
+    let n = data.len();
+    assert!(
+        n.is_power_of_two(),
+        "fft length must be a power of two, got {}",
+        n
+    );
+
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let w = Complex::from_polar(T::ONE, sign * T::TWO * T::PI / T::from_f64(len as f64));
+        for chunk in data.chunks_mut(len) {
+            let mut t = Complex::ONE;
+            let (lo, hi) = chunk.split_at_mut(len / 2);
+            for (a, b) in lo.iter_mut().zip(hi.iter_mut()) {
+                let u = *a;
+                let v = *b * t;
+                *a = u + v;
+                *b = u - v;
+                t *= w;
+            }
+        }
+        len <<= 1;
+    }
+}
@@ -0,0 +1,245 @@
+//! float
+//!
+//! This module contains the `Float` trait, which abstracts over the floating point
+//! types that can be used as the components of a complex number.
+//!
+//! With the default `std` feature the math functions come from the standard
+//! library. Without it they are routed through the `libm` crate, so the crate
+//! works on `no_std` targets with an identical API.
+//!
+//! # Examples
+//!
+//! ```
+//! use xcomplex::float::Float;
+//!
+//! fn hypot<T: Float>(a: T, b: T) -> T {
+//!     (a * a + b * b).sqrt()
+//! }
+//!
+//! assert_eq!(hypot(3.0_f32, 4.0), 5.0);
+//! assert_eq!(hypot(3.0_f64, 4.0), 5.0);
+//! ```
+
+use core::cmp::Ordering;
+use core::fmt::Debug;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A floating point type usable as the component of a complex number.
+///
+/// This trait is implemented for `f32` and `f64`.
+pub trait Float:
+    Copy
+    + Debug
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// The value `0`.
+    const ZERO: Self;
+    /// The value `1`.
+    const ONE: Self;
+    /// The value `2`.
+    const TWO: Self;
+    /// Archimedes' constant `π`.
+    const PI: Self;
+    /// Not a number.
+    const NAN: Self;
+    /// The difference between `1` and the next larger representable number.
+    const EPSILON: Self;
+
+    /// Convert an `f64` to this type, rounding if necessary.
+    fn from_f64(x: f64) -> Self;
+    /// Return the absolute value.
+    fn abs(self) -> Self;
+    /// Return `1` or `-1` depending on the sign.
+    fn signum(self) -> Self;
+    /// Return the magnitude of `self` with the sign of `sign`.
+    fn copysign(self, sign: Self) -> Self;
+    /// Return the nearest integer, rounding half-way cases away from zero.
+    fn round(self) -> Self;
+    /// Return the nearest integer, rounding half-way cases to the even integer.
+    fn round_ties_even(self) -> Self;
+    /// Return the largest integer less than or equal to this value.
+    fn floor(self) -> Self;
+    /// Return the smallest integer greater than or equal to this value.
+    fn ceil(self) -> Self;
+    /// Return the integer part, rounding towards zero.
+    fn trunc(self) -> Self;
+    /// Return the square root.
+    fn sqrt(self) -> Self;
+    /// Return `sqrt(self^2 + other^2)` without intermediate overflow or underflow.
+    fn hypot(self, other: Self) -> Self;
+    /// Return `e` raised to this value.
+    fn exp(self) -> Self;
+    /// Return `e` raised to this value, minus one, accurately near zero.
+    fn exp_m1(self) -> Self;
+    /// Return the natural logarithm.
+    fn ln(self) -> Self;
+    /// Return the natural logarithm of one plus this value, accurately near zero.
+    fn ln_1p(self) -> Self;
+    /// Return this value raised to a floating point power.
+    fn powf(self, n: Self) -> Self;
+    /// Return this value raised to an integer power.
+    fn powi(self, n: i32) -> Self;
+    /// Return the sine.
+    fn sin(self) -> Self;
+    /// Return the cosine.
+    fn cos(self) -> Self;
+    /// Return the hyperbolic sine.
+    fn sinh(self) -> Self;
+    /// Return the hyperbolic cosine.
+    fn cosh(self) -> Self;
+    /// Return the four quadrant arctangent of `self` (y) and `other` (x).
+    fn atan2(self, other: Self) -> Self;
+    /// Return `self * a + b` computed with a single rounding.
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    /// Convert this value from degrees to radians.
+    fn to_radians(self) -> Self;
+    /// Convert this value from radians to degrees.
+    fn to_degrees(self) -> Self;
+    /// Return the raw bit pattern, zero-extended to 64 bits.
+    fn to_bits(self) -> u64;
+    /// Return the IEEE 754 total ordering between `self` and `other`.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+    /// Return whether this value is NaN.
+    fn is_nan(self) -> bool;
+    /// Return whether this value is positive or negative infinity.
+    fn is_infinite(self) -> bool;
+    /// Return whether this value is neither infinite nor NaN.
+    fn is_finite(self) -> bool;
+}
+
+#[cfg(feature = "std")]
+macro_rules! math {
+    ($t:ident, $std:ident, $libm:ident, $($arg:expr),*) => {
+        $t::$std($($arg),*)
+    };
+}
+
+#[cfg(not(feature = "std"))]
+macro_rules! math {
+    ($t:ident, $std:ident, $libm:ident, $($arg:expr),*) => {
+        libm::Libm::<$t>::$libm($($arg),*)
+    };
+}
+
+macro_rules! impl_float {
+    ($t:ident) => {
+        impl Float for $t {
+            const ZERO: Self = 0.0;
+            const ONE: Self = 1.0;
+            const TWO: Self = 2.0;
+            const PI: Self = core::$t::consts::PI;
+            const NAN: Self = $t::NAN;
+            const EPSILON: Self = $t::EPSILON;
+
+            fn from_f64(x: f64) -> Self {
+                x as $t
+            }
+            fn abs(self) -> Self {
+                math!($t, abs, fabs, self)
+            }
+            fn signum(self) -> Self {
+                if self.is_nan() {
+                    self
+                } else if self.is_sign_negative() {
+                    -1.0
+                } else {
+                    1.0
+                }
+            }
+            fn copysign(self, sign: Self) -> Self {
+                math!($t, copysign, copysign, self, sign)
+            }
+            fn round(self) -> Self {
+                math!($t, round, round, self)
+            }
+            fn round_ties_even(self) -> Self {
+                math!($t, round_ties_even, rint, self)
+            }
+            fn floor(self) -> Self {
+                math!($t, floor, floor, self)
+            }
+            fn ceil(self) -> Self {
+                math!($t, ceil, ceil, self)
+            }
+            fn trunc(self) -> Self {
+                math!($t, trunc, trunc, self)
+            }
+            fn sqrt(self) -> Self {
+                math!($t, sqrt, sqrt, self)
+            }
+            fn hypot(self, other: Self) -> Self {
+                math!($t, hypot, hypot, self, other)
+            }
+            fn exp(self) -> Self {
+                math!($t, exp, exp, self)
+            }
+            fn exp_m1(self) -> Self {
+                math!($t, exp_m1, expm1, self)
+            }
+            fn ln(self) -> Self {
+                math!($t, ln, log, self)
+            }
+            fn ln_1p(self) -> Self {
+                math!($t, ln_1p, log1p, self)
+            }
+            fn powf(self, n: Self) -> Self {
+                math!($t, powf, pow, self, n)
+            }
+            #[cfg(feature = "std")]
+            fn powi(self, n: i32) -> Self {
+                $t::powi(self, n)
+            }
+            #[cfg(not(feature = "std"))]
+            fn powi(self, n: i32) -> Self {
+                libm::Libm::<$t>::pow(self, n as $t)
+            }
+            fn sin(self) -> Self {
+                math!($t, sin, sin, self)
+            }
+            fn cos(self) -> Self {
+                math!($t, cos, cos, self)
+            }
+            fn sinh(self) -> Self {
+                math!($t, sinh, sinh, self)
+            }
+            fn cosh(self) -> Self {
+                math!($t, cosh, cosh, self)
+            }
+            fn atan2(self, other: Self) -> Self {
+                math!($t, atan2, atan2, self, other)
+            }
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                math!($t, mul_add, fma, self, a, b)
+            }
+            fn to_radians(self) -> Self {
+                $t::to_radians(self)
+            }
+            fn to_degrees(self) -> Self {
+                $t::to_degrees(self)
+            }
+            fn to_bits(self) -> u64 {
+                $t::to_bits(self).into()
+            }
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                $t::total_cmp(self, other)
+            }
+            fn is_nan(self) -> bool {
+                $t::is_nan(self)
+            }
+            fn is_infinite(self) -> bool {
+                $t::is_infinite(self)
+            }
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+        }
+    };
+}
+
+impl_float!(f32);
+impl_float!(f64);
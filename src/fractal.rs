@@ -0,0 +1,103 @@
+//! fractal
+//!
+//! This module contains escape-time iterations of the quadratic map `z -> z^2 + c`,
+//! as used to render the Mandelbrot set and Julia sets.
+//!
+//! # Examples
+//!
+//! ```
+//! use xcomplex::fractal::escape_time;
+//! use xcomplex::grid::grid;
+//!
+//! let points = grid((-2.0, 0.5), (-1.25, 1.25), 5, 5);
+//! let row: String = points[10..15]
+//!     .iter()
+//!     .map(|c| if escape_time(*c, 100, 2.0).is_none() { '#' } else { '.' })
+//!     .collect();
+//! assert_eq!(row, "####.");
+//! ```
+//!
+//! # References
+//!
+//! * [Mandelbrot set](https://en.wikipedia.org/wiki/Mandelbrot_set)
+//! * [Julia set](https://en.wikipedia.org/wiki/Julia_set)
+
+use crate::float::Float;
+use crate::number::Complex;
+
+/// Return the first iteration at which the Mandelbrot orbit of `c` leaves the disk of radius `bound`.
+/// # Examples
+/// ```
+/// use xcomplex::fractal::escape_time;
+/// use xcomplex::number::Complex;
+/// assert_eq!(escape_time(Complex::new(0.0, 0.0), 1000, 2.0), None);
+/// assert_eq!(escape_time(Complex::new(-1.0, 0.0), 1000, 2.0), None);
+/// assert_eq!(escape_time(Complex::new(10.0, 10.0), 1000, 2.0), Some(1));
+/// assert_eq!(escape_time(Complex::new(1.0, 0.0), 1000, 2.0), Some(3));
+/// assert_eq!(escape_time(Complex::new(1.0, 0.0), 2, 2.0), None);
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// Starting from `z = 0`, iteration `n` computes `z = z^2 + c` and returns
+/// `Some(n)` as soon as `z.norm() > bound`, counting from one. If the orbit stays
+/// bounded for `max_iter` iterations the result is `None`.
+pub fn escape_time<T: Float>(c: Complex<T>, max_iter: usize, bound: T) -> Option<usize> {
+    iterate(Complex::ZERO, c, max_iter, bound)
+}
+
+/// Return the first iteration at which the orbit of `z0` under `z -> z^2 + c` leaves the disk of radius `bound`.
+/// # Examples
+/// ```
+/// use xcomplex::fractal::{escape_time, julia_escape_time};
+/// use xcomplex::number::Complex;
+/// let c = Complex::new(0.0, 1.0);
+/// // 0 -> i -> -1 + i -> -i -> -1 + i -> ... is bounded
+/// assert_eq!(julia_escape_time(Complex::new(0.0, 0.0), c, 1000, 2.0), None);
+/// // 1 -> 1 + i -> 3i -> escapes
+/// assert_eq!(julia_escape_time(Complex::new(1.0, 0.0), c, 1000, 2.0), Some(2));
+/// // for c = 0 the Julia set is the unit circle
+/// let zero = Complex::new(0.0, 0.0);
+/// assert_eq!(julia_escape_time(Complex::new(0.6, 0.6), zero, 1000, 2.0), None);
+/// assert!(julia_escape_time(Complex::new(0.8, 0.8), zero, 1000, 2.0).is_some());
+/// // starting at zero gives the Mandelbrot iteration
+/// let c = Complex::new(0.3, 0.5);
+/// assert_eq!(julia_escape_time(zero, c, 1000, 2.0), escape_time(c, 1000, 2.0));
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// This function shares its iteration with `escape_time`, which starts from
+/// `z0 = 0`. The starting point itself is not tested against `bound`.
+pub fn julia_escape_time<T: Float>(
+    z0: Complex<T>,
+    c: Complex<T>,
+    max_iter: usize,
+    bound: T,
+) -> Option<usize> {
+    iterate(z0, c, max_iter, bound)
+}
+
+fn iterate<T: Float>(z0: Complex<T>, c: Complex<T>, max_iter: usize, bound: T) -> Option<usize> {
+    let bound_sqr = bound * bound;
+    let mut z = z0;
+    for n in 1..=max_iter {
+        z = z * z + c;
+        if z.norm_sqr() > bound_sqr {
+            return Some(n);
+        }
+    }
+    None
+}
@@ -0,0 +1,130 @@
+//! gaussian
+//!
+//! This module contains number theory on Gaussian integers, the complex numbers
+//! whose components are both integers, together with `Complex::gaussian` and
+//! `Complex::is_gaussian_integer`.
+//!
+//! # Examples
+//!
+//! ```
+//! use xcomplex::gaussian::gcd;
+//! use xcomplex::number::Complex;
+//!
+//! // 3 + 4i = (2 + i)^2 and 2 + 11i = (2 + i)^3 have gcd (2 + i)^2 up to a unit
+//! let g = gcd(Complex::new(3.0, 4.0), Complex::new(2.0, 11.0));
+//! assert_eq!(g.norm_sqr(), 25.0);
+//! ```
+//!
+//! # References
+//!
+//! * [Gaussian integer](https://en.wikipedia.org/wiki/Gaussian_integer)
+
+use crate::float::Float;
+use crate::number::Complex;
+
+const GCD_MAX_STEPS: usize = 4096;
+
+/// Return a greatest common divisor of two Gaussian integers.
+/// # Examples
+/// ```
+/// use xcomplex::gaussian::gcd;
+/// use xcomplex::number::Complex;
+/// // 5 + 3i = (1 + i)(4 - i) and 2 - 8i = -i (1 + i)^2 (4 + i) share only 1 + i
+/// let g = gcd(Complex::new(5.0, 3.0), Complex::new(2.0, -8.0));
+/// let units = [
+///     Complex::new(1.0, 0.0),
+///     Complex::new(-1.0, 0.0),
+///     Complex::new(0.0, 1.0),
+///     Complex::new(0.0, -1.0),
+/// ];
+/// assert!(units.iter().any(|u| *u * g == Complex::new(1.0, 1.0)));
+/// assert_eq!(Complex::new(5.0, 3.0) % g, Complex::new(0.0, 0.0));
+/// assert_eq!(Complex::new(2.0, -8.0) % g, Complex::new(0.0, 0.0));
+/// let a = Complex::new(6.0, -4.0);
+/// assert_eq!(gcd(a, Complex::new(0.0, 0.0)), a);
+/// // non-finite components have no divisors to find
+/// assert!(gcd(Complex::new(1.0, 0.0), Complex::new(f64::INFINITY, 0.0)).is_nan());
+/// assert!(gcd(Complex::new(f64::NAN, 0.0), Complex::new(2.0, 0.0)).is_nan());
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// This function runs the Euclidean algorithm with the rounded remainder `%`,
+/// whose quotient is rounded like `Complex::round` with ties to even, until the
+/// remainder is zero. The result
+/// is only determined up to multiplication by one of the units `1, -1, i, -i`.
+/// Components must be integers well inside the exactly representable range of
+/// `T` for the remainders to stay exact. Every step at least halves the norm, so
+/// the loop is capped at 4096 steps. If either input is not finite, or the cap
+/// is reached, the result is NaN in both components.
+pub fn gcd<T: Float>(a: Complex<T>, b: Complex<T>) -> Complex<T> {
+    let nan = Complex::new(T::NAN, T::NAN);
+    if !a.is_finite() || !b.is_finite() {
+        return nan;
+    }
+    let (mut a, mut b) = (a, b);
+    for _ in 0..GCD_MAX_STEPS {
+        if b == Complex::ZERO {
+            return a;
+        }
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    nan
+}
+
+impl<T: Float> Complex<T> {
+    /// Create a Gaussian integer from integer components.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let g: Complex = Complex::gaussian(3, -4);
+    /// assert_eq!(g, Complex::new(3.0, -4.0));
+    /// assert!(g.is_gaussian_integer());
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// The conversion is exact for components up to `2^53` in magnitude for
+    /// `f64` and `2^24` for `f32`; larger values are rounded.
+    pub fn gaussian(re: i64, im: i64) -> Self {
+        Self::new(T::from_f64(re as f64), T::from_f64(im as f64))
+    }
+
+    /// Return whether both components are integers.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// assert!(Complex::new(2.0, -7.0).is_gaussian_integer());
+    /// assert!(Complex::new(0.0, 0.0).is_gaussian_integer());
+    /// assert!(!Complex::new(2.0, 0.5).is_gaussian_integer());
+    /// assert!(!Complex::new(f64::INFINITY, 1.0).is_gaussian_integer());
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function checks that the number equals its `round()` and is finite,
+    /// so infinities and NaN are never Gaussian integers.
+    pub fn is_gaussian_integer(&self) -> bool {
+        self.is_finite() && self.round() == *self
+    }
+}
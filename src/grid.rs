@@ -0,0 +1,75 @@
+//! grid
+//!
+//! This module contains helpers for sampling rectangular regions of the complex
+//! plane, for example to plot a function.
+//!
+//! # Examples
+//!
+//! ```
+//! use xcomplex::grid::grid;
+//!
+//! let points = grid((-1.0, 1.0), (-1.0, 1.0), 21, 21);
+//! let inside = points.iter().filter(|z| z.norm() <= 1.0).count();
+//! assert_eq!(points.len(), 441);
+//! assert!(inside > 300 && inside < 400);
+//! ```
+
+use alloc::vec::Vec;
+
+use crate::float::Float;
+use crate::number::Complex;
+
+/// Return evenly spaced sample points covering a rectangle, one row per imaginary value.
+/// # Examples
+/// ```
+/// use xcomplex::grid::grid;
+/// use xcomplex::number::Complex;
+/// let points = grid((-2.0, 1.0), (0.0, 1.0), 4, 3);
+/// assert_eq!(points.len(), 4 * 3);
+/// assert_eq!(points[0], Complex::new(-2.0, 0.0));
+/// assert_eq!(points[3], Complex::new(1.0, 0.0));
+/// assert_eq!(points[8], Complex::new(-2.0, 1.0));
+/// assert_eq!(points[11], Complex::new(1.0, 1.0));
+/// assert_eq!(points[5], Complex::new(-1.0, 0.5));
+/// assert_eq!(grid((0.0, 1.0), (0.0, 1.0), 1, 2), vec![Complex::new(0.0, 0.0), Complex::new(0.0, 1.0)]);
+/// assert!(grid((0.0, 1.0), (0.0, 1.0), 0, 5).is_empty());
+/// assert_eq!(grid((0.1, 0.7), (0.0, 0.0), 7, 1)[6], Complex::new(0.7, 0.0));
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// The result is row-major: point `j * re_steps + k` has the `k`th real value and
+/// the `j`th imaginary value. Both ranges include their endpoints; with a single
+/// step only the start of the range is sampled.
+pub fn grid<T: Float>(
+    re_range: (T, T),
+    im_range: (T, T),
+    re_steps: usize,
+    im_steps: usize,
+) -> Vec<Complex<T>> {
+    let mut points = Vec::with_capacity(re_steps * im_steps);
+    for j in 0..im_steps {
+        let im = sample(im_range, j, im_steps);
+        for k in 0..re_steps {
+            points.push(Complex::new(sample(re_range, k, re_steps), im));
+        }
+    }
+    points
+}
+
+fn sample<T: Float>((start, end): (T, T), i: usize, steps: usize) -> T {
+    if steps < 2 {
+        return start;
+    }
+    if i == steps - 1 {
+        return end;
+    }
+    let t = T::from_f64(i as f64) / T::from_f64((steps - 1) as f64);
+    start + (end - start) * t
+}
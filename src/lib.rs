@@ -1 +1,55 @@
+//! xcomplex
+//!
+//! A complex number library.
+//!
+//! # Features
+//!
+//! * `std` (default): use the standard library for floating point math. Without it
+//!   the crate is `no_std` and routes the math through the `libm` crate; the public
+//!   API is identical in both modes.
+//! * `serde`: implement `Serialize` and `Deserialize` for `Complex`.
+//! * `num-traits`: implement `num_traits::Zero` and `num_traits::One` for `Complex`.
+//! * `rayon`: add parallel slice helpers such as `slice::par_map`. Implies `std`.
+//!
+//! # Examples
+//!
+//! The core operations produce the same results with and without `std`, which
+//! `cargo test --no-default-features` checks:
+//!
+//! ```
+//! use xcomplex::number::Complex;
+//!
+//! let c = Complex::new(1.0, 2.0);
+//! let d = Complex::new(3.0, 4.0);
+//!
+//! assert_eq!(c * d, Complex::new(-5.0, 10.0));
+//! assert_eq!(c / d, Complex::new(0.44, 0.08));
+//! assert_eq!(d.norm(), 5.0);
+//! assert!(c.sqrt().powi(2).approx_eq(&c, 1e-12));
+//! assert!(c.ln().exp().approx_eq(&c, 1e-12));
+//! assert!(c.sin().asin().approx_eq(&c, 1e-12));
+//! assert!(Complex::cis(1.0).approx_eq(&Complex::new(0.5403023058681398, 0.8414709848078965), 1e-15));
+//! ```
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+mod macros;
+
+pub mod bytes;
+pub mod fft;
+pub mod float;
+pub mod fractal;
+pub mod gaussian;
+pub mod grid;
+pub mod matrix;
+pub mod mobius;
 pub mod number;
+pub mod phase;
+pub mod poly;
+pub mod prelude;
+pub mod rotation;
+pub mod slice;
+pub mod solve;
+pub mod special;
+mod trig;
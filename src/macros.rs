@@ -0,0 +1,48 @@
+//! macros
+//!
+//! This module contains the assertion macros exported from the crate root.
+
+/// Assert that both components of two complex numbers differ by at most `epsilon`.
+///
+/// On failure the panic message shows both values and the component-wise
+/// differences.
+/// # Examples
+/// ```
+/// use xcomplex::assert_complex_eq;
+/// use xcomplex::number::Complex;
+/// let c = Complex::new(1.0, 2.0);
+/// assert_complex_eq!(c.sqrt() * c.sqrt(), c, 1e-12);
+/// assert_complex_eq!(c.ln().exp(), c, 1e-12);
+/// ```
+/// ```should_panic
+/// use xcomplex::assert_complex_eq;
+/// use xcomplex::number::Complex;
+/// assert_complex_eq!(Complex::new(1.0, 2.0), Complex::new(1.0, 2.5), 1e-9);
+/// ```
+/// # Panics
+/// This macro panics if either component differs by more than `epsilon`.
+/// # Safety
+/// This macro is safe.
+/// # Aborts
+/// This macro does not abort.
+/// # Undefined Behavior
+/// This macro does not cause undefined behavior.
+#[macro_export]
+macro_rules! assert_complex_eq {
+    ($left:expr, $right:expr, $epsilon:expr $(,)?) => {
+        match (&$left, &$right, &$epsilon) {
+            (left, right, epsilon) => {
+                if !left.approx_eq(right, *epsilon) {
+                    ::core::panic!(
+                        "assertion `left ≈ right` failed (epsilon: {:?})\n  left: {:?}\n right: {:?}\n  diff: re {:?}, im {:?}",
+                        epsilon,
+                        left,
+                        right,
+                        left.re - right.re,
+                        left.im - right.im,
+                    );
+                }
+            }
+        }
+    };
+}
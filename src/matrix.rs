@@ -0,0 +1,118 @@
+//! matrix
+//!
+//! This module contains functions operating on small dense matrices of complex
+//! numbers, stored as a `Vec` of rows.
+//!
+//! # Examples
+//!
+//! ```
+//! use xcomplex::matrix::conj_transpose;
+//! use xcomplex::number::Complex;
+//!
+//! let m = vec![vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)]];
+//! let h = conj_transpose(&m);
+//! assert_eq!(h, vec![vec![Complex::new(1.0, -2.0)], vec![Complex::new(3.0, -4.0)]]);
+//! ```
+//!
+//! # References
+//!
+//! * [Conjugate transpose](https://en.wikipedia.org/wiki/Conjugate_transpose)
+
+use alloc::vec::Vec;
+
+use crate::float::Float;
+use crate::number::Complex;
+
+/// Return the conjugate transpose (Hermitian adjoint) of a matrix.
+/// # Examples
+/// ```
+/// use xcomplex::matrix::conj_transpose;
+/// use xcomplex::number::Complex;
+/// let m = vec![
+///     vec![Complex::new(1.0, 1.0), Complex::new(2.0, -2.0), Complex::new(3.0, 0.0)],
+///     vec![Complex::new(0.0, 4.0), Complex::new(5.0, 5.0), Complex::new(-6.0, 1.0)],
+/// ];
+/// let h = conj_transpose(&m);
+/// assert_eq!(h.len(), 3);
+/// assert!(h.iter().all(|row| row.len() == 2));
+/// for i in 0..2 {
+///     for j in 0..3 {
+///         assert_eq!(h[j][i], m[i][j].conj());
+///     }
+/// }
+/// ```
+/// # Panics
+/// This function panics if the rows have different lengths.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+pub fn conj_transpose<T: Float>(m: &[Vec<Complex<T>>]) -> Vec<Vec<Complex<T>>> {
+    let cols = columns(m);
+    (0..cols)
+        .map(|j| m.iter().map(|row| row[j].conj()).collect())
+        .collect()
+}
+
+/// Return the matrix product of two matrices.
+/// # Examples
+/// ```
+/// use xcomplex::matrix::matmul;
+/// use xcomplex::number::Complex;
+/// let a = vec![
+///     vec![Complex::new(1.0, 1.0), Complex::new(0.0, 2.0)],
+///     vec![Complex::new(3.0, 0.0), Complex::new(-1.0, 1.0)],
+/// ];
+/// let b = vec![
+///     vec![Complex::new(2.0, 0.0), Complex::new(1.0, -1.0)],
+///     vec![Complex::new(0.0, 1.0), Complex::new(4.0, 0.0)],
+/// ];
+/// let expected = [
+///     [Complex::new(0.0, 2.0), Complex::new(2.0, 8.0)],
+///     [Complex::new(5.0, -1.0), Complex::new(-1.0, 1.0)],
+/// ];
+/// let c = matmul(&a, &b);
+/// for i in 0..2 {
+///     for j in 0..2 {
+///         assert!(c[i][j].approx_eq(&expected[i][j], 1e-12));
+///     }
+/// }
+/// ```
+/// ```should_panic
+/// use xcomplex::matrix::matmul;
+/// use xcomplex::number::Complex;
+/// let a = vec![vec![Complex::new(1.0, 0.0); 3]; 2];
+/// matmul(&a, &a);
+/// ```
+/// # Panics
+/// This function panics if the number of columns of `a` differs from the number
+/// of rows of `b`, or if the rows of either matrix have different lengths.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+pub fn matmul<T: Float>(a: &[Vec<Complex<T>>], b: &[Vec<Complex<T>>]) -> Vec<Vec<Complex<T>>> {
+    let inner = columns(a);
+    let cols = columns(b);
+    assert_eq!(inner, b.len(), "matrix dimensions do not match");
+    a.iter()
+        .map(|row| {
+            (0..cols)
+                .map(|j| row.iter().zip(b.iter()).map(|(x, r)| *x * r[j]).sum())
+                .collect()
+        })
+        .collect()
+}
+
+fn columns<T>(m: &[Vec<T>]) -> usize {
+    let cols = m.first().map_or(0, |row| row.len());
+    assert!(
+        m.iter().all(|row| row.len() == cols),
+        "matrix rows have different lengths"
+    );
+    cols
+}
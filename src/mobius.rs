@@ -0,0 +1,146 @@
+//! mobius
+//!
+//! This module contains Möbius transformations `z -> (a z + b) / (c z + d)` of the
+//! extended complex plane.
+//!
+//! # Examples
+//!
+//! ```
+//! use xcomplex::mobius::Mobius;
+//! use xcomplex::number::Complex;
+//!
+//! // the Cayley transform maps the upper half-plane onto the unit disk
+//! let i = Complex::new(0.0, 1.0);
+//! let one = Complex::new(1.0, 0.0);
+//! let cayley = Mobius { a: one, b: -i, c: one, d: i };
+//! assert!(cayley.apply(Complex::new(0.3, 2.0)).norm() < 1.0);
+//! assert_eq!(cayley.apply(i), Complex::new(0.0, 0.0));
+//! ```
+//!
+//! # References
+//!
+//! * [Möbius transformation](https://en.wikipedia.org/wiki/M%C3%B6bius_transformation)
+
+use crate::float::Float;
+use crate::number::Complex;
+
+/// A Möbius transformation `z -> (a z + b) / (c z + d)`.
+/// # Examples
+/// ```
+/// use xcomplex::mobius::Mobius;
+/// use xcomplex::number::Complex;
+/// let m = Mobius {
+///     a: Complex::new(2.0, 0.0),
+///     b: Complex::new(0.0, 1.0),
+///     c: Complex::new(0.0, 0.0),
+///     d: Complex::new(1.0, 0.0),
+/// };
+/// assert_eq!(m.apply(Complex::new(1.0, 1.0)), Complex::new(2.0, 3.0));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Mobius<T = f64> {
+    pub a: Complex<T>,
+    pub b: Complex<T>,
+    pub c: Complex<T>,
+    pub d: Complex<T>,
+}
+
+impl<T: Float> PartialEq for Mobius<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.a == other.a && self.b == other.b && self.c == other.c && self.d == other.d
+    }
+}
+
+impl<T: Float> Mobius<T> {
+    /// Return the identity transformation `z -> z`.
+    /// # Examples
+    /// ```
+    /// use xcomplex::mobius::Mobius;
+    /// use xcomplex::number::Complex;
+    /// let id: Mobius = Mobius::identity();
+    /// let z = Complex::new(-1.5, 2.5);
+    /// assert_eq!(id.apply(z), z);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn identity() -> Self {
+        Self {
+            a: Complex::ONE,
+            b: Complex::ZERO,
+            c: Complex::ZERO,
+            d: Complex::ONE,
+        }
+    }
+
+    /// Apply the transformation to a complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::mobius::Mobius;
+    /// use xcomplex::number::Complex;
+    /// let one = Complex::new(1.0, 0.0);
+    /// let zero = Complex::new(0.0, 0.0);
+    /// // z -> 1 / z
+    /// let inversion = Mobius { a: zero, b: one, c: one, d: zero };
+    /// assert_eq!(inversion.apply(Complex::new(0.0, 2.0)), Complex::new(0.0, -0.5));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// The pole `z = -d / c` produces a non-finite result.
+    pub fn apply(&self, z: Complex<T>) -> Complex<T> {
+        (self.a * z + self.b) / (self.c * z + self.d)
+    }
+
+    /// Return the transformation that applies `other` first and then `self`.
+    /// # Examples
+    /// ```
+    /// use xcomplex::mobius::Mobius;
+    /// use xcomplex::number::Complex;
+    /// let f = Mobius {
+    ///     a: Complex::new(1.0, 1.0),
+    ///     b: Complex::new(2.0, 0.0),
+    ///     c: Complex::new(0.0, 1.0),
+    ///     d: Complex::new(3.0, -1.0),
+    /// };
+    /// let g = Mobius {
+    ///     a: Complex::new(0.5, 0.0),
+    ///     b: Complex::new(0.0, -1.0),
+    ///     c: Complex::new(1.0, 0.0),
+    ///     d: Complex::new(2.0, 2.0),
+    /// };
+    /// let z = Complex::new(0.7, -1.2);
+    /// assert!(f.compose(&g).apply(z).approx_eq(&f.apply(g.apply(z)), 1e-12));
+    /// assert_eq!(f.compose(&Mobius::identity()), f);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// The coefficients are those of the matrix product
+    /// `[[a, b], [c, d]] * [[other.a, other.b], [other.c, other.d]]`.
+    pub fn compose(&self, other: &Self) -> Self {
+        Self {
+            a: self.a * other.a + self.b * other.c,
+            b: self.a * other.b + self.b * other.d,
+            c: self.c * other.a + self.d * other.c,
+            d: self.c * other.b + self.d * other.d,
+        }
+    }
+}
@@ -21,10 +21,10 @@
 //! assert_eq!(c.arg(), 1.1071487177940904);
 //!
 //! assert_eq!(c.conj(), Complex::new(1.0, -2.0));
-//! assert_eq!(c.exp(), Complex::new(-1.1312043837568135, 2.4717266720048188));
+//! assert!(c.exp().approx_eq(&Complex::new(-1.1312043837568135, 2.4717266720048188), 1e-15));
 //! assert_eq!(c.powf(PI), Complex::new(-11.826467250438055, -4.138504280918663));
-//! assert_eq!(c.powi(2), Complex::new(-3.0, 4.000000000000002));
-//! assert_eq!(c.powc(Complex::new(2.0, 3.0)), Complex::new(-7.041080062171126, -7.259799175444256));
+//! assert_eq!(c.powi(2), Complex::new(-3.0, 4.0));
+//! assert_eq!(c.powc(Complex::new(2.0, 3.0)), Complex::new(-0.01513267242272266, -0.179867483913335));
 //! assert_eq!(c.ln(), Complex::new(0.8047189562170503, 1.1071487177940904));
 //! assert_eq!(c.sqrt(), Complex::new(1.272019649514069, 0.7861513777574233));
 //! ```
@@ -33,20 +33,1647 @@
 //!
 //! * [Complex numbers](https://en.wikipedia.org/wiki/Complex_number)
 
-use std::ops::{Add, Div, Mul, Sub};
+use core::cmp::Ordering;
+use core::error::Error;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::{Product, Sum};
+use core::num::ParseFloatError;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+use core::str::FromStr;
 
+use alloc::vec::Vec;
+
+use crate::float::Float;
+
+/// A complex number `re + im * i`.
+///
+/// The components are of any [`Float`] type and default to `f64`, so `Complex`
+/// alone names `Complex<f64>`. See also [`Complex32`] and [`Complex64`].
+///
+/// The struct is `#[repr(C)]`, so it has the same layout as `[T; 2]`, with the
+/// real part first. This makes it safe to pass to C code expecting interleaved
+/// `[re, im]` pairs.
+///
+/// With the `serde` feature enabled, a complex number serializes as `{"re": .., "im": ..}`.
+/// # Examples
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// use xcomplex::number::Complex;
+/// let c = Complex::new(1.0, 2.0);
+/// let json = serde_json::to_string(&c).unwrap();
+/// assert_eq!(json, r#"{"re":1.0,"im":2.0}"#);
+/// assert_eq!(serde_json::from_str::<Complex>(&json).unwrap(), c);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Complex<T = f64> {
+    pub re: T,
+    pub im: T,
+}
+
+/// A complex number with `f32` components.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex32;
+/// let c = Complex32::new(1.0, 2.0);
+/// let d = Complex32::new(3.0, 4.0);
+/// assert_eq!(c * d, Complex32::new(-5.0, 10.0));
+/// ```
+pub type Complex32 = Complex<f32>;
+
+/// A complex number with `f64` components.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex64;
+/// let c = Complex64::new(1.0, 2.0);
+/// let d = Complex64::new(3.0, 4.0);
+/// assert_eq!(c * d, Complex64::new(-5.0, 10.0));
+/// ```
+pub type Complex64 = Complex<f64>;
+
+/// A complex number ordered by its magnitude.
+///
+/// `Complex` itself has no ordering; wrap values in `ByMagnitude` to sort them by
+/// `norm_sqr`, compared with `total_cmp`. Two values are equal when their
+/// magnitudes are.
+/// # Examples
+/// ```
+/// use xcomplex::number::{ByMagnitude, Complex};
+/// let mut v: Vec<ByMagnitude> = [
+///     Complex::new(3.0, 4.0),
+///     Complex::new(0.0, -1.0),
+///     Complex::new(-2.0, 0.5),
+///     Complex::new(0.1, 0.1),
+///     Complex::new(-6.0, -8.0),
+/// ]
+/// .into_iter()
+/// .map(ByMagnitude)
+/// .collect();
+/// v.sort();
+/// assert!(v.windows(2).all(|w| w[0].0.norm() <= w[1].0.norm()));
+/// assert_eq!(v[0].0, Complex::new(0.1, 0.1));
+/// assert_eq!(v[4].0, Complex::new(-6.0, -8.0));
+/// assert_eq!(ByMagnitude(Complex::new(1.0, 0.0)), ByMagnitude(Complex::new(0.0, -1.0)));
+/// ```
 #[derive(Debug, Clone, Copy)]
-pub struct Complex {
-    pub re: f64,
-    pub im: f64,
+pub struct ByMagnitude<T = f64>(pub Complex<T>);
+
+impl<T: Float> PartialEq for ByMagnitude<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: Float> Eq for ByMagnitude<T> {}
+
+impl<T: Float> PartialOrd for ByMagnitude<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Float> Ord for ByMagnitude<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.norm_sqr().total_cmp(&other.0.norm_sqr())
+    }
+}
+
+/// Return the imaginary unit `i`.
+/// # Examples
+/// ```
+/// use xcomplex::number::{i, Complex};
+/// assert_eq!(3.0 + 4.0 * i(), Complex::new(3.0, 4.0));
+/// assert_eq!(i::<f64>() * i(), Complex::new(-1.0, 0.0));
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// This function returns `Complex::I`.
+pub fn i<T: Float>() -> Complex<T> {
+    Complex::I
 }
 
-impl Complex {
-    /// Create a new complex number.
+impl<T: Float> Complex<T> {
+    /// The complex number `0 + 0i`.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert_eq!(Complex::ZERO + c, c);
+    /// ```
+    pub const ZERO: Self = Self::new(T::ZERO, T::ZERO);
+
+    /// The complex number `1 + 0i`.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert_eq!(Complex::ONE * c, c);
+    /// ```
+    pub const ONE: Self = Self::new(T::ONE, T::ZERO);
+
+    /// The imaginary unit `0 + 1i`.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex64;
+    /// assert_eq!(Complex64::I * Complex64::I, -Complex64::ONE);
+    /// ```
+    pub const I: Self = Self::new(T::ZERO, T::ONE);
+
+    /// Create a new complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function is implemented as a simple wrapper around the `Complex` struct.
+    pub const fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+
+    /// Return the components of the complex number as a `(re, im)` tuple.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.5, -2.0);
+    /// assert_eq!(c.to_tuple(), (1.5, -2.0));
+    /// assert_eq!(Complex::from(c.to_tuple()), c);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function is the inverse of `From<(T, T)>`.
+    pub const fn to_tuple(&self) -> (T, T) {
+        (self.re, self.im)
+    }
+
+    /// Return a view of the complex number as a `[re, im]` array.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert_eq!(c.as_array(), &[1.0, 2.0]);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// `Complex` is `#[repr(C)]` with two fields of type `T`, so it has the same
+    /// size, alignment and field order as `[T; 2]` and the reference is
+    /// reinterpreted without copying.
+    pub fn as_array(&self) -> &[T; 2] {
+        // SAFETY: `Complex<T>` is `#[repr(C)]` and consists of exactly two `T`s.
+        unsafe { &*(self as *const Self as *const [T; 2]) }
+    }
+
+    /// Return a mutable view of the complex number as a `[re, im]` array.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let mut c = Complex::new(1.0, 2.0);
+    /// let parts = c.as_array_mut();
+    /// parts[0] = -3.0;
+    /// parts[1] *= 4.0;
+    /// assert_eq!(c.re, -3.0);
+    /// assert_eq!(c.im, 8.0);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// See `as_array` for the layout guarantee.
+    pub fn as_array_mut(&mut self) -> &mut [T; 2] {
+        // SAFETY: `Complex<T>` is `#[repr(C)]` and consists of exactly two `T`s.
+        unsafe { &mut *(self as *mut Self as *mut [T; 2]) }
+    }
+
+    /// Create a new complex number from its polar form.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c: Complex = Complex::new(1.0, 2.0);
+    /// let d = Complex::from_polar(c.norm(), c.arg());
+    /// assert!((d.re - c.re).abs() < 1e-12);
+    /// assert!((d.im - c.im).abs() < 1e-12);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn from_polar(r: T, theta: T) -> Self {
+        Self {
+            re: r * theta.cos(),
+            im: r * theta.sin(),
+        }
+    }
+
+    /// Create a new complex number from its norm and argument in degrees.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// assert!(Complex::from_polar_deg(1.0, 90.0).approx_eq(&Complex::new(0.0, 1.0), 1e-12));
+    /// assert!(Complex::from_polar_deg(2.0, -45.0).approx_eq(&Complex::from_polar(2.0, -std::f64::consts::FRAC_PI_4), 1e-12));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn from_polar_deg(r: T, degrees: T) -> Self {
+        Self::from_polar(r, degrees.to_radians())
+    }
+
+    /// Create the unit complex number `exp(i * theta)`.
+    /// # Examples
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use xcomplex::number::Complex;
+    /// assert!(Complex::cis(PI).approx_eq(&Complex::new(-1.0, 0.0), 1e-12));
+    /// assert!(Complex::cis(2.0).approx_eq(&Complex::new(0.0, 2.0).exp(), 1e-12));
+    /// assert!((Complex::cis(123.4_f64).norm() - 1.0).abs() < 1e-12);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn cis(theta: T) -> Self {
+        Self {
+            re: theta.cos(),
+            im: theta.sin(),
+        }
+    }
+
+    /// Return the norm of the complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert_eq!(c.norm(), 2.23606797749979);
+    /// let big: f64 = Complex::new(1e200, 1e200).norm();
+    /// assert!(big.is_finite());
+    /// assert!((big / 1e200 - 2.0_f64.sqrt()).abs() < 1e-15);
+    /// let small: f64 = Complex::new(3e-200, 4e-200).norm();
+    /// assert!((small / 1e-200 - 5.0).abs() < 1e-15);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function uses `hypot`, so it neither overflows nor underflows when the
+    /// squared components would.
+    pub fn norm(&self) -> T {
+        self.re.hypot(self.im)
+    }
+
+    /// Return the squared norm of the complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c: Complex = Complex::new(1.0, 2.0);
+    /// assert_eq!(c.norm_sqr(), 5.0);
+    /// assert!((c.norm_sqr() - c.norm() * c.norm()).abs() < 1e-12);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function skips the square root taken by `norm`, which makes it
+    /// cheaper when only comparing magnitudes.
+    pub fn norm_sqr(&self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Return the Euclidean distance between two complex numbers.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let a: Complex = Complex::new(1.0, 0.0);
+    /// let b = Complex::new(0.0, 1.0);
+    /// assert!((a.distance(&b) - 2.0_f64.sqrt()).abs() < 1e-12);
+    /// assert_eq!(a.distance(&a), 0.0);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn distance(&self, other: &Self) -> T {
+        (*self - *other).norm()
+    }
+
+    /// Return the squared Euclidean distance between two complex numbers.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let a = Complex::new(1.0, 0.0);
+    /// let b = Complex::new(0.0, 1.0);
+    /// assert_eq!(a.distance_sqr(&b), 2.0);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn distance_sqr(&self, other: &Self) -> T {
+        (*self - *other).norm_sqr()
+    }
+
+    /// Return the absolute error `(self - other).norm()` of the complex number against `other`.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(4.0, 6.0);
+    /// let d = Complex::new(1.0, 2.0);
+    /// assert_eq!(c.abs_diff(&d), 5.0);
+    /// assert_eq!(c.abs_diff(&d), c.distance(&d));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn abs_diff(&self, other: &Self) -> T {
+        (*self - *other).norm()
+    }
+
+    /// Return the error of the complex number relative to the reference value `other`.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(4.0, 6.0);
+    /// let d = Complex::new(3.0, 4.0);
+    /// assert_eq!(c.rel_diff(&d), c.abs_diff(&d) / 5.0);
+    /// let z = Complex::new(0.0, 0.0);
+    /// assert_eq!(d.rel_diff(&z), 5.0);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function returns `abs_diff(other) / other.norm()`. When `other` is zero
+    /// the relative error is undefined and the absolute error is returned instead.
+    pub fn rel_diff(&self, other: &Self) -> T {
+        let diff = self.abs_diff(other);
+        let scale = other.norm();
+        if scale == T::ZERO {
+            diff
+        } else {
+            diff / scale
+        }
+    }
+
+    /// Return the linear interpolation `self * (1 - t) + other * t`.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let a = Complex::new(1.0, 2.0);
+    /// let b = Complex::new(3.0, -6.0);
+    /// assert_eq!(a.lerp(&b, 0.0), a);
+    /// assert_eq!(a.lerp(&b, 1.0), b);
+    /// assert_eq!(a.lerp(&b, 0.5), Complex::new(2.0, -2.0));
+    /// assert_eq!(a.lerp(&b, 2.0), Complex::new(5.0, -14.0));
+    /// assert_eq!(a.lerp(&b, -1.0), Complex::new(-1.0, 10.0));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn lerp(&self, other: &Self, t: T) -> Self {
+        self.scale(T::ONE - t) + other.scale(t)
+    }
+
+    /// Return the argument of the complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert_eq!(c.arg(), 1.1071487177940904);
+    /// assert_eq!(Complex::new(-1.0, 0.0).arg(), std::f64::consts::PI);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function uses `atan2`, so the result lies in `(-PI, PI]`. A negative
+    /// zero imaginary part on the negative real axis yields `-PI`.
+    pub fn arg(&self) -> T {
+        Self::arg_of(self.re, self.im)
+    }
+
+    /// Return the argument of the complex number with the given real and imaginary parts.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// for (re, im) in [(1.0, 2.0), (-1.0, 0.0), (-1.0, -0.0), (0.0, -3.0), (-2.5, 0.5)] {
+    ///     assert_eq!(Complex::arg_of(re, im), Complex::new(re, im).arg());
+    /// }
+    /// assert_eq!(Complex::arg_of(-1.0, -0.0), -std::f64::consts::PI);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function returns `im.atan2(re)`, the same branch convention as `arg`:
+    /// the result lies in `(-PI, PI]`, except that a negative zero imaginary part
+    /// on the negative real axis yields `-PI`.
+    pub fn arg_of(re: T, im: T) -> T {
+        im.atan2(re)
+    }
+
+    /// Return the norm of the complex number together with its unit direction.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(-1.5, 2.0);
+    /// let (mag, unit) = c.to_polar_unit();
+    /// assert_eq!(mag, 2.5);
+    /// assert_eq!(unit, c.signum());
+    /// assert!((unit * mag).approx_eq(&c, 1e-12));
+    /// assert_eq!(Complex::new(0.0, 0.0).to_polar_unit(), (0.0, Complex::ZERO));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// The unit is `ZERO` for the zero input, matching `signum`.
+    pub fn to_polar_unit(&self) -> (T, Self) {
+        let r = self.norm();
+        if r == T::ZERO {
+            return (r, Self::ZERO);
+        }
+        (r, *self / r)
+    }
+
+    /// Return the argument of the complex number in degrees, in `(-180, 180]`.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c: Complex = Complex::new(0.0, 1.0);
+    /// assert!((c.arg_deg() - 90.0).abs() < 1e-12);
+    /// assert_eq!(Complex::new(-1.0, 0.0).arg_deg(), 180.0);
+    /// assert_eq!(Complex::new(-1.0, -0.0).arg_deg(), 180.0);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn arg_deg(&self) -> T {
+        let half_turn = T::from_f64(180.0);
+        let d = self.arg().to_degrees();
+        if d <= -half_turn {
+            d + T::TWO * half_turn
+        } else {
+            d
+        }
+    }
+
+    /// Return the polar form `(norm, arg)` of the complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c: Complex = Complex::new(1.0, 2.0);
+    /// let (r, theta) = c.to_polar();
+    /// assert_eq!(r, 2.23606797749979);
+    /// assert_eq!(theta, 1.1071487177940904);
+    /// let d = Complex::from_polar(r, theta);
+    /// assert!((d.re - c.re).abs() < 1e-12);
+    /// assert!((d.im - c.im).abs() < 1e-12);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn to_polar(&self) -> (T, T) {
+        (self.norm(), self.arg())
+    }
+
+    /// Return the complex conjugate of the complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert_eq!(c.conj(), Complex::new(1.0, -2.0));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn conj(&self) -> Self {
+        Self {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+
+    /// Return the complex number reflected across the real axis.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert_eq!(c.reflect_real(), Complex::new(1.0, -2.0));
+    /// assert_eq!(c.reflect_real(), c.conj());
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn reflect_real(&self) -> Self {
+        self.conj()
+    }
+
+    /// Return the complex number reflected across the imaginary axis.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert_eq!(c.reflect_imag(), Complex::new(-1.0, 2.0));
+    /// assert_eq!(c.reflect_imag(), -c.conj());
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn reflect_imag(&self) -> Self {
+        Self {
+            re: -self.re,
+            im: self.im,
+        }
+    }
+
+    /// Return the complex number reflected across the origin.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert_eq!(c.reflect_origin(), Complex::new(-1.0, -2.0));
+    /// assert_eq!(c.reflect_origin(), c.reflect_real().reflect_imag());
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn reflect_origin(&self) -> Self {
+        -*self
+    }
+
+    /// Return the complex number with both components multiplied by a real factor.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.5, -2.0);
+    /// assert_eq!(c.scale(2.0), Complex::new(3.0, -4.0));
+    /// assert_eq!(c.scale(2.0).unscale(2.0), c);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn scale(&self, factor: T) -> Self {
+        Self {
+            re: self.re * factor,
+            im: self.im * factor,
+        }
+    }
+
+    /// Return the complex number with both components divided by a real factor.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(3.0, -4.0);
+    /// assert_eq!(c.unscale(2.0), Complex::new(1.5, -2.0));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn unscale(&self, factor: T) -> Self {
+        Self {
+            re: self.re / factor,
+            im: self.im / factor,
+        }
+    }
+
+    /// Return the quotient `self / rhs`, or `None` if `rhs` is zero.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// let d = Complex::new(3.0, 4.0);
+    /// assert_eq!(c.checked_div(d), Some(c / d));
+    /// assert_eq!(c.checked_div(Complex::ZERO), None);
+    /// let tiny = Complex::new(1e-200, 0.0);
+    /// assert_eq!(Complex::new(1.0, 0.0).checked_div(tiny), Some(Complex::new(1e200, 0.0)));
+    /// let huge = Complex::new(1e200, 0.0);
+    /// assert_eq!(Complex::new(1.0, 0.0).checked_div(huge), Some(Complex::new(1e-200, 0.0)));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn checked_div(&self, rhs: Self) -> Option<Self> {
+        if rhs.re == T::ZERO && rhs.im == T::ZERO {
+            None
+        } else {
+            Some(*self / rhs)
+        }
+    }
+
+    /// Return `self * a + b` with reduced rounding error.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// let a = Complex::new(3.0, 4.0);
+    /// let b = Complex::new(5.0, 6.0);
+    /// assert_eq!(c.mul_add(a, b), Complex::new(0.0, 16.0));
+    /// let c = Complex::new(0.1, 0.7);
+    /// let a = Complex::new(1.0 / 3.0, -0.2);
+    /// assert!(c.mul_add(a, b).approx_eq(&(c * a + b), 1e-15));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// The result equals `self * a + b` mathematically, but each component is
+    /// evaluated with fused multiply-adds.
+    pub fn mul_add(&self, a: Self, b: Self) -> Self {
+        Self {
+            re: self.re.mul_add(a.re, (-self.im).mul_add(a.im, b.re)),
+            im: self.re.mul_add(a.im, self.im.mul_add(a.re, b.im)),
+        }
+    }
+
+    /// Return the complex number rotated counterclockwise about the origin by `angle` radians.
+    /// # Examples
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 0.0);
+    /// assert!(c.rotate(PI / 2.0).approx_eq(&Complex::new(0.0, 1.0), 1e-12));
+    /// let d: Complex = Complex::new(3.0, -4.0);
+    /// assert!((d.rotate(0.7).norm() - d.norm()).abs() < 1e-12);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn rotate(&self, angle: T) -> Self {
+        *self * Self::cis(angle)
+    }
+
+    /// Return the reciprocal `1 / z` of the complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert_eq!(c.inv(), Complex::new(0.2, -0.4));
+    /// assert!((c * c.inv()).approx_eq(&Complex::new(1.0, 0.0), 1e-12));
+    /// let z: Complex = Complex::ZERO;
+    /// assert!(z.inv().re.is_nan() && z.inv().im.is_nan());
+    /// assert_eq!(Complex::new(1e-200, 0.0).inv(), Complex::new(1e200, 0.0));
+    /// assert_eq!(Complex::new(0.0, 1e200).inv(), Complex::new(0.0, -1e-200));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function computes `ONE / self` with the scaled division of the `Div`
+    /// impl, so tiny and huge inputs do not overflow or underflow on the way. The
+    /// zero input yields NaN components.
+    pub fn inv(&self) -> Self {
+        Self::ONE / *self
+    }
+
+    /// Return the complex number scaled to unit norm, or `ZERO` for the zero input.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(3.0, 4.0);
+    /// assert_eq!(c.signum(), Complex::new(0.6, 0.8));
+    /// let d: Complex = Complex::new(1.0, 2.0);
+    /// assert!((d.signum().norm() - 1.0).abs() < 1e-12);
+    /// assert_eq!(Complex::new(0.0, 0.0).signum(), Complex::new(0.0, 0.0));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn signum(&self) -> Self {
+        let r = self.norm();
+        if r == T::ZERO {
+            return Self::ZERO;
+        }
+        *self / r
+    }
+
+    /// Return the unit complex number with the same argument as the complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(3.0, 4.0);
+    /// assert!(c.phasor().approx_eq(&c.signum(), 1e-15));
+    /// let z: Complex = Complex::ZERO;
+    /// assert_eq!(z.phasor(), Complex::ONE);
+    /// assert_eq!(z.signum(), Complex::ZERO);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function returns `cis(arg())`. Since `arg` of zero is zero, the zero
+    /// input maps to `ONE`, where `signum` returns `ZERO`.
+    pub fn phasor(&self) -> Self {
+        Self::cis(self.arg())
+    }
+
+    /// Return the complex number with its norm capped at `max`, keeping its direction.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(3.0, 4.0);
+    /// assert_eq!(c.clamp_norm(10.0), c);
+    /// assert_eq!(c.clamp_norm(5.0), c);
+    /// let d = c.clamp_norm(2.5);
+    /// assert_eq!(d, Complex::new(1.5, 2.0));
+    /// assert_eq!(d.norm(), 2.5);
+    /// assert_eq!(Complex::new(0.0, 0.0).clamp_norm(0.0), Complex::new(0.0, 0.0));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// Numbers with a norm above `max` are replaced by `signum() * max`, so the zero
+    /// input always returns `ZERO`.
+    pub fn clamp_norm(&self, max: T) -> Self {
+        if self.norm() <= max {
+            return *self;
+        }
+        self.signum() * max
+    }
+
+    /// Return the complex number with each component rounded to the nearest integer.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// assert_eq!(Complex::new(2.4, -1.6).round(), Complex::new(2.0, -2.0));
+    /// assert_eq!(Complex::new(2.5, -0.5).round(), Complex::new(2.0, 0.0));
+    /// assert_eq!(Complex::new(3.5, -1.5).round(), Complex::new(4.0, -2.0));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// Half-way cases round to the even integer, so the result is the nearest
+    /// Gaussian integer with ties broken without bias.
+    pub fn round(&self) -> Self {
+        Self {
+            re: self.re.round_ties_even(),
+            im: self.im.round_ties_even(),
+        }
+    }
+
+    /// Return the complex number with each component rounded down.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// assert_eq!(Complex::new(2.4, -1.6).floor(), Complex::new(2.0, -2.0));
+    /// assert_eq!(Complex::new(-0.5, 3.0).floor(), Complex::new(-1.0, 3.0));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn floor(&self) -> Self {
+        Self {
+            re: self.re.floor(),
+            im: self.im.floor(),
+        }
+    }
+
+    /// Return the complex number with each component rounded up.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// assert_eq!(Complex::new(2.4, -1.6).ceil(), Complex::new(3.0, -1.0));
+    /// assert_eq!(Complex::new(-0.5, 3.0).ceil(), Complex::new(0.0, 3.0));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn ceil(&self) -> Self {
+        Self {
+            re: self.re.ceil(),
+            im: self.im.ceil(),
+        }
+    }
+
+    /// Return the complex number with each component rounded towards zero.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// assert_eq!(Complex::new(2.4, -1.6).trunc(), Complex::new(2.0, -1.0));
+    /// assert_eq!(Complex::new(-0.5, 3.9).trunc(), Complex::new(0.0, 3.0));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn trunc(&self) -> Self {
+        Self {
+            re: self.re.trunc(),
+            im: self.im.trunc(),
+        }
+    }
+
+    /// Return whether either component is NaN.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// assert!(!Complex::new(1.0, 2.0).is_nan());
+    /// assert!(!Complex::new(f64::INFINITY, 2.0).is_nan());
+    /// assert!(Complex::new(1.0, f64::NAN).is_nan());
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn is_nan(&self) -> bool {
+        self.re.is_nan() || self.im.is_nan()
+    }
+
+    /// Return whether either component is infinite and neither is NaN.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// assert!(!Complex::new(1.0, 2.0).is_infinite());
+    /// assert!(Complex::new(f64::INFINITY, 2.0).is_infinite());
+    /// assert!(!Complex::new(f64::INFINITY, f64::NAN).is_infinite());
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn is_infinite(&self) -> bool {
+        !self.is_nan() && (self.re.is_infinite() || self.im.is_infinite())
+    }
+
+    /// Return whether both components are finite.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// assert!(Complex::new(1.0, 2.0).is_finite());
+    /// assert!(!Complex::new(1.0, f64::NEG_INFINITY).is_finite());
+    /// assert!(!Complex::new(f64::NAN, 2.0).is_finite());
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn is_finite(&self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+
+    /// Return whether both components differ from those of `other` by at most `epsilon`.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert_ne!(c.powf(2.0), Complex::new(-3.0, 4.0));
+    /// assert!(c.powf(2.0).approx_eq(&Complex::new(-3.0, 4.0), 1e-9));
+    /// assert!(!c.approx_eq(&Complex::new(1.0, 2.1), 1e-9));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn approx_eq(&self, other: &Self, epsilon: T) -> bool {
+        (self.re - other.re).abs() <= epsilon && (self.im - other.im).abs() <= epsilon
+    }
+
+    /// Return whether both components have the same bit pattern as those of `other`.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let pos = Complex::new(0.0, 1.0);
+    /// let neg = Complex::new(-0.0, 1.0);
+    /// assert_eq!(pos, neg);
+    /// assert!(!pos.bit_eq(&neg));
+    /// assert!(pos.bit_eq(&pos));
+    /// let nan = Complex::new(f64::NAN, 2.0);
+    /// assert_ne!(nan, nan);
+    /// assert!(nan.bit_eq(&nan));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// Unlike `PartialEq`, this comparison is reflexive and tells signed zeros
+    /// apart. NaNs compare equal only if their payloads match.
+    pub fn bit_eq(&self, other: &Self) -> bool {
+        self.re.to_bits() == other.re.to_bits() && self.im.to_bits() == other.im.to_bits()
+    }
+
+    /// Return whether the imaginary part is at most `epsilon` in absolute value.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(2.0, 1e-15);
+    /// assert!(c.is_real(1e-12));
+    /// assert!(!c.is_real(0.0));
+    /// assert!(!c.is_imaginary(1e-12));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn is_real(&self, epsilon: T) -> bool {
+        self.im.abs() <= epsilon
+    }
+
+    /// Return whether the real part is at most `epsilon` in absolute value.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(-1e-15, 3.0);
+    /// assert!(c.is_imaginary(1e-12));
+    /// assert!(!c.is_imaginary(0.0));
+    /// assert!(!c.is_real(1e-12));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn is_imaginary(&self, epsilon: T) -> bool {
+        self.re.abs() <= epsilon
+    }
+
+    /// Return the exponential of the complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert!(c.exp().approx_eq(&Complex::new(-1.1312043837568135, 2.4717266720048188), 1e-15));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn exp(&self) -> Self {
+        let e = self.re.exp();
+        Self {
+            re: e * self.im.cos(),
+            im: e * self.im.sin(),
+        }
+    }
+
+    /// Return the exponential of the complex number split into its magnitude and unit phase.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// let (mag, phase) = c.exp_polar();
+    /// assert!((mag - std::f64::consts::E).abs() < 1e-15);
+    /// assert_eq!(phase, Complex::cis(2.0));
+    /// assert!((phase * mag).approx_eq(&c.exp(), 1e-15));
+    /// // the growth factor can overflow on its own while the phase stays usable
+    /// let (mag, phase): (f64, Complex) = Complex::new(1000.0, 0.5).exp_polar();
+    /// assert!(mag.is_infinite());
+    /// assert_eq!(phase, Complex::cis(0.5));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function returns `(exp(re), cis(im))`, whose product is `exp`.
+    pub fn exp_polar(&self) -> (T, Self) {
+        (self.re.exp(), Self::cis(self.im))
+    }
+
+    /// Return `exp(z) - 1`, accurate for small `z`.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let z: Complex = Complex::new(1e-10, 1e-10);
+    /// let expected = Complex::new(1e-10, 1.0000000001e-10);
+    /// let naive = z.exp() - Complex::ONE;
+    /// assert!((z.expm1().re - expected.re).abs() < 1e-25);
+    /// assert!((z.expm1().im - expected.im).abs() < 1e-25);
+    /// assert!((naive.re - expected.re).abs() > 1e-20);
+    /// assert!(Complex::new(1.0, 2.0).expm1().approx_eq(&(Complex::new(1.0, 2.0).exp() - Complex::ONE), 1e-15));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// The real part is computed as `expm1(re) * cos(im) - 2 * sin(im / 2)^2`,
+    /// which avoids subtracting nearly equal values.
+    pub fn expm1(&self) -> Self {
+        let s = (self.im / T::TWO).sin();
+        Self {
+            re: self.re.exp_m1() * self.im.cos() - T::TWO * s * s,
+            im: self.re.exp() * self.im.sin(),
+        }
+    }
+
+    /// Return the complex number raised to the power of a real number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// use std::f64::consts::PI;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert_eq!(c.powf(PI), Complex::new(-11.826467250438055, -4.138504280918663));
+    /// assert!(c.powf(2.0).approx_eq(&(c * c), 1e-12));
+    /// assert!(Complex::new(-1.0, 0.0).powf(0.5).approx_eq(&Complex::new(0.0, 1.0), 1e-12));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function computes the principal branch `r^n * cis(n * arg(z))` with
+    /// `arg(z)` in `(-PI, PI]`, so the result jumps when `z` crosses the negative
+    /// real axis.
+    pub fn powf(&self, n: T) -> Self {
+        let r = self.norm();
+        let theta = self.arg();
+        let e = r.powf(n);
+        Self {
+            re: e * (theta * n).cos(),
+            im: e * (theta * n).sin(),
+        }
+    }
+
+    /// Return the complex number with its norm and argument raised to separate powers.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c: Complex = Complex::new(1.0, 2.0);
+    /// assert_eq!(c.pow_split(2.0, 2.0), c.powf(2.0));
+    /// assert!(c.pow_split(0.5, 0.5).approx_eq(&c.sqrt(), 1e-15));
+    /// // keep the phase, square the magnitude
+    /// let d = c.pow_split(2.0, 1.0);
+    /// assert!((d.norm() - 5.0).abs() < 1e-12);
+    /// assert!((d.arg() - c.arg()).abs() < 1e-15);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function computes `from_polar(norm()^mag_pow, arg() * phase_pow)`,
+    /// using the principal argument in `(-PI, PI]`.
+    pub fn pow_split(&self, mag_pow: T, phase_pow: T) -> Self {
+        Self::from_polar(self.norm().powf(mag_pow), self.arg() * phase_pow)
+    }
+
+    /// Return the complex number raised to the power of an integer.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert_eq!(c.powi(2), Complex::new(-3.0, 4.0));
+    /// assert_eq!(c.powi(3), Complex::new(-11.0, -2.0));
+    /// assert_eq!(c.powi(0), Complex::new(1.0, 0.0));
+    /// assert_eq!(c.powi(-1), c.inv());
+    /// assert!(c.powi(-2).approx_eq(&Complex::new(-0.12, -0.16), 1e-15));
+    /// assert_eq!(Complex::new(1e-200, 0.0).powi(-1), Complex::new(1e200, 0.0));
+    /// assert_eq!(Complex::new(1e200, 0.0).powi(-1), Complex::new(1e-200, 0.0));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function raises the number to `|n|` with `powu` and takes the
+    /// reciprocal of the result for negative `n`.
+    pub fn powi(&self, n: i32) -> Self {
+        let p = self.powu(n.unsigned_abs());
+        if n < 0 {
+            p.inv()
+        } else {
+            p
+        }
+    }
+
+    /// Return the complex number raised to the power of an unsigned integer.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert_eq!(c.powu(2), Complex::new(-3.0, 4.0));
+    /// assert_eq!(c.powi(2), c.powu(2));
+    /// assert_eq!(c.powu(5), Complex::new(41.0, -38.0));
+    /// assert_eq!(c.powu(0), Complex::new(1.0, 0.0));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function uses binary exponentiation by repeated multiplication, so it
+    /// is exact for Gaussian integers as long as the result is representable.
+    pub fn powu(&self, mut n: u32) -> Self {
+        let mut base = *self;
+        let mut acc = Self::ONE;
+        while n > 0 {
+            if n & 1 == 1 {
+                acc *= base;
+            }
+            n >>= 1;
+            if n > 0 {
+                base *= base;
+            }
+        }
+        acc
+    }
+
+    /// Return the natural logarithm of the complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert_eq!(c.ln(), Complex::new(0.8047189562170503, 1.1071487177940904));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn ln(&self) -> Self {
+        Self {
+            re: self.norm().ln(),
+            im: self.arg(),
+        }
+    }
+
+    /// Return the natural logarithm of the complex number on the branch with winding `k`.
+    /// # Examples
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert_eq!(c.ln_branch(0), c.ln());
+    /// let d = c.ln_branch(1) - c.ln_branch(0);
+    /// assert!(d.approx_eq(&Complex::new(0.0, 2.0 * PI), 1e-15));
+    /// assert!(c.ln_branch(-3).exp().approx_eq(&c, 1e-12));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// The imaginary part is `arg(z) + 2 * PI * k`. The branch `k = 0` is the
+    /// principal branch returned by `ln`.
+    pub fn ln_branch(&self, k: i32) -> Self {
+        Self {
+            re: self.norm().ln(),
+            im: self.arg() + T::TWO * T::PI * T::from_f64(k.into()),
+        }
+    }
+
+    /// Return `ln(1 + z)`, accurate for small `z`.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let z: Complex = Complex::new(1e-10, 1e-10);
+    /// let expected = Complex::new(1e-10, 9.999999999e-11);
+    /// assert!((z.ln_1p().re - expected.re).abs() < 1e-25);
+    /// assert!((z.ln_1p().im - expected.im).abs() < 1e-25);
+    /// assert!(Complex::new(1.0, 2.0).ln_1p().approx_eq(&Complex::new(2.0, 2.0).ln(), 1e-15));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// The real part is computed as `ln_1p(2 re + re^2 + im^2) / 2`, which avoids
+    /// forming `1 + z` explicitly.
+    pub fn ln_1p(&self) -> Self {
+        let t = T::TWO * self.re + self.norm_sqr();
+        Self {
+            re: t.ln_1p() / T::TWO,
+            im: self.im.atan2(T::ONE + self.re),
+        }
+    }
+
+    /// Return the logarithm of the complex number to a real base.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(100.0, 0.0);
+    /// assert!(c.log(10.0).approx_eq(&Complex::new(2.0, 0.0), 1e-12));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function computes `ln(z) / ln(base)`, so it inherits the branch cut of
+    /// `ln` along the negative real axis.
+    pub fn log(&self, base: T) -> Self {
+        self.ln() / base.ln()
+    }
+
+    /// Return the logarithm of the complex number to a complex base.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(-4.0, 0.0);
+    /// let base = Complex::new(0.0, 2.0);
+    /// assert!(c.log_c(base).approx_eq(&(c.ln() / base.ln()), 1e-12));
+    /// assert!(Complex::new(100.0, 0.0).log_c(Complex::new(10.0, 0.0)).approx_eq(&Complex::new(2.0, 0.0), 1e-12));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function computes `ln(z) / ln(base)`, so it inherits the branch cut of
+    /// `ln` along the negative real axis for both `z` and `base`.
+    pub fn log_c(&self, base: Self) -> Self {
+        self.ln() / base.ln()
+    }
+
+    /// Return the complex number raised to the power of another complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert_eq!(c.powc(Complex::new(2.0, 3.0)), Complex::new(-0.01513267242272266, -0.179867483913335));
+    /// assert!(c.powc(Complex::new(2.0, 0.0)).approx_eq(&c.powi(2), 1e-12));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn powc(&self, n: Self) -> Self {
+        (n * self.ln()).exp()
+    }
+
+    /// Return a real base raised to the power of the complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(0.0, 1.0);
+    /// assert!(c.exp_base(std::f64::consts::E).approx_eq(&Complex::cis(1.0), 1e-15));
+    /// assert!(Complex::new(3.0, 0.0).exp_base(2.0).approx_eq(&Complex::new(8.0, 0.0), 1e-12));
+    /// let z = Complex::new(1.5, -0.5);
+    /// assert!(z.exp_base(10.0).approx_eq(&Complex::new(10.0, 0.0).powc(z), 1e-12));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function computes `exp(z ln(base))`. For negative bases the real
+    /// logarithm is NaN; use `powc` with a complex base instead.
+    pub fn exp_base(&self, base: T) -> Self {
+        (*self * base.ln()).exp()
+    }
+
+    /// Return the square root of the complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert_eq!(c.sqrt(), Complex::new(1.272019649514069, 0.7861513777574233));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn sqrt(&self) -> Self {
+        let r = self.norm();
+        let theta = self.arg();
+        Self {
+            re: r.sqrt() * (theta / T::TWO).cos(),
+            im: r.sqrt() * (theta / T::TWO).sin(),
+        }
+    }
+
+    /// Return both square roots of the complex number, principal root first.
     /// # Examples
     /// ```
     /// use xcomplex::number::Complex;
+    /// let (a, b) = Complex::new(-1.0, 0.0).sqrt_both();
+    /// assert!(a.approx_eq(&Complex::new(0.0, 1.0), 1e-15));
+    /// assert!(b.approx_eq(&Complex::new(0.0, -1.0), 1e-15));
     /// let c = Complex::new(1.0, 2.0);
+    /// let (a, b) = c.sqrt_both();
+    /// assert_eq!(a, c.sqrt());
+    /// assert!((a * a).approx_eq(&c, 1e-12));
+    /// assert!((b * b).approx_eq(&c, 1e-12));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn sqrt_both(&self) -> (Self, Self) {
+        let s = self.sqrt();
+        (s, -s)
+    }
+
+    /// Return the principal cube root of the complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(-8.0, 0.0).cbrt();
+    /// assert!(c.approx_eq(&Complex::new(1.0, 3.0_f64.sqrt()), 1e-12));
+    /// assert!((c * c * c).approx_eq(&Complex::new(-8.0, 0.0), 1e-12));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn cbrt(&self) -> Self {
+        let three = T::from_f64(3.0);
+        Self::from_polar(self.norm().powf(T::ONE / three), self.arg() / three)
+    }
+
+    /// Return all `n` complex nth roots of the complex number, starting from the principal root.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let roots = Complex::new(1.0, 0.0).nth_roots(4);
+    /// let expected = [
+    ///     Complex::new(1.0, 0.0),
+    ///     Complex::new(0.0, 1.0),
+    ///     Complex::new(-1.0, 0.0),
+    ///     Complex::new(0.0, -1.0),
+    /// ];
+    /// assert_eq!(roots.len(), 4);
+    /// for (root, e) in roots.iter().zip(expected.iter()) {
+    ///     assert!(root.approx_eq(e, 1e-12));
+    ///     assert!(root.powi(4).approx_eq(&Complex::new(1.0, 0.0), 1e-12));
+    /// }
     /// ```
     /// # Panics
     /// This function does not panic.
@@ -57,17 +1684,58 @@ impl Complex {
     /// # Undefined Behavior
     /// This function does not cause undefined behavior.
     /// # Implementation Details
-    /// This function is implemented as a simple wrapper around the `Complex` struct.
-    pub fn new(re: f64, im: f64) -> Self {
-        Self { re, im }
+    /// The roots are spaced by `2 * PI / n` in argument. For `n == 0` the result is empty.
+    pub fn nth_roots(&self, n: u32) -> Vec<Self> {
+        if n == 0 {
+            return Vec::new();
+        }
+        let m = T::from_f64(n as f64);
+        let r = self.norm().powf(T::ONE / m);
+        let theta = self.arg();
+        (0..n)
+            .map(|k| Self::from_polar(r, (theta + T::TWO * T::PI * T::from_f64(k as f64)) / m))
+            .collect()
     }
 
-    /// Return the norm of the complex number.
+    /// Return the principal nth root of the complex number together with all `n` roots.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(-8.0, 8.0);
+    /// let (principal, roots) = c.roots_with_principal(3);
+    /// assert_eq!(roots.len(), 3);
+    /// assert_eq!(principal, c.nth_roots(3)[0]);
+    /// assert!(principal.approx_eq(&c.cbrt(), 1e-12));
+    /// ```
+    /// ```should_panic
+    /// use xcomplex::number::Complex;
+    /// Complex::new(1.0, 0.0).roots_with_principal(0);
+    /// ```
+    /// # Panics
+    /// This function panics if `n` is zero, since there is no principal zeroth root.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// The roots come from a single call to `nth_roots`, so `r^(1/n)` and the
+    /// argument are computed once; the principal root is its first element.
+    pub fn roots_with_principal(&self, n: u32) -> (Self, Vec<Self>) {
+        assert!(n > 0, "there is no principal zeroth root");
+        let roots = self.nth_roots(n);
+        (roots[0], roots)
+    }
+
+    /// Return the sine of the complex number.
     /// # Examples
     /// ```
     /// use xcomplex::number::Complex;
     /// let c = Complex::new(1.0, 2.0);
-    /// assert_eq!(c.norm(), 2.23606797749979);
+    /// assert_eq!(c.sin(), Complex::new(3.165778513216168, 1.9596010414216063));
+    /// assert_eq!(Complex::new(1.0, 0.0).sin(), Complex::new(1.0_f64.sin(), 0.0));
+    /// assert_eq!(Complex::new(0.0, 2.0).sin(), Complex::new(0.0, 2.0_f64.sinh()));
     /// ```
     /// # Panics
     /// This function does not panic.
@@ -77,16 +1745,21 @@ impl Complex {
     /// This function does not abort.
     /// # Undefined Behavior
     /// This function does not cause undefined behavior.
-    pub fn norm(&self) -> f64 {
-        (self.re * self.re + self.im * self.im).sqrt()
+    pub fn sin(&self) -> Self {
+        Self {
+            re: self.re.sin() * self.im.cosh(),
+            im: self.re.cos() * self.im.sinh(),
+        }
     }
 
-    /// Return the argument of the complex number.
+    /// Return the cosine of the complex number.
     /// # Examples
     /// ```
     /// use xcomplex::number::Complex;
     /// let c = Complex::new(1.0, 2.0);
-    /// assert_eq!(c.arg(), 1.1071487177940904);
+    /// assert_eq!(c.cos(), Complex::new(2.0327230070196656, -3.0518977991518));
+    /// assert_eq!(Complex::new(1.0, 0.0).cos(), Complex::new(1.0_f64.cos(), -0.0));
+    /// assert_eq!(Complex::new(0.0, 2.0).cos(), Complex::new(2.0_f64.cosh(), -0.0));
     /// ```
     /// # Panics
     /// This function does not panic.
@@ -96,16 +1769,26 @@ impl Complex {
     /// This function does not abort.
     /// # Undefined Behavior
     /// This function does not cause undefined behavior.
-    pub fn arg(&self) -> f64 {
-        self.im.atan2(self.re)
+    pub fn cos(&self) -> Self {
+        Self {
+            re: self.re.cos() * self.im.cosh(),
+            im: -(self.re.sin() * self.im.sinh()),
+        }
     }
 
-    /// Return the complex conjugate of the complex number.
+    /// Return the tangent of the complex number.
     /// # Examples
     /// ```
     /// use xcomplex::number::Complex;
     /// let c = Complex::new(1.0, 2.0);
-    /// assert_eq!(c.conj(), Complex::new(1.0, -2.0));
+    /// assert_eq!(c.tan(), Complex::new(0.03381282607989669, 1.0147936161466335));
+    /// assert!(c.tan().approx_eq(&(c.sin() / c.cos()), 1e-15));
+    /// assert_eq!(Complex::new(1.0, 0.0).tan(), Complex::new(1.557407724654902, 0.0));
+    /// assert_eq!(Complex::new(1.0, 1000.0).tan(), Complex::new(0.0, 1.0));
+    /// assert_eq!(Complex::new(1.0, -1000.0).tan(), Complex::new(0.0, -1.0));
+    /// let t: Complex = Complex::new(1.0, 25.0).tan();
+    /// assert_eq!(t.im, 1.0);
+    /// assert!((t.re - 3.5076145474880305e-22).abs() <= 1e-15 * 3.5076145474880305e-22);
     /// ```
     /// # Panics
     /// This function does not panic.
@@ -115,19 +1798,36 @@ impl Complex {
     /// This function does not abort.
     /// # Undefined Behavior
     /// This function does not cause undefined behavior.
-    pub fn conj(&self) -> Self {
+    /// # Implementation Details
+    /// For large imaginary parts `sin / cos` overflows, so this function uses the
+    /// bounded form `(sin(2x) + i sinh(2y)) / (cos(2x) + cosh(2y))`. Beyond
+    /// `|y| > 20` the imaginary part rounds to `±1` and the real part decays like
+    /// `2 sin(2x) exp(-2|y|)`, which is used directly before `cosh` overflows.
+    pub fn tan(&self) -> Self {
+        let (x2, y2) = (T::TWO * self.re, T::TWO * self.im);
+        if self.im.abs() > T::from_f64(20.0) {
+            return Self {
+                re: T::TWO * x2.sin() * (-y2.abs()).exp(),
+                im: self.im.signum(),
+            };
+        }
+        let d = x2.cos() + y2.cosh();
         Self {
-            re: self.re,
-            im: -self.im,
+            re: x2.sin() / d,
+            im: y2.sinh() / d,
         }
     }
 
-    /// Return the exponential of the complex number.
+    /// Return the unnormalized sinc function `sin(z) / z` of the complex number.
     /// # Examples
     /// ```
+    /// use std::f64::consts::PI;
     /// use xcomplex::number::Complex;
+    /// assert_eq!(Complex::new(0.0, 0.0).sinc(), Complex::new(1.0, 0.0));
+    /// assert!(Complex::new(PI, 0.0).sinc().norm() < 1e-15);
     /// let c = Complex::new(1.0, 2.0);
-    /// assert_eq!(c.exp(), Complex::new(-1.1312043837568135, 2.4717266720048188));
+    /// assert_eq!(c.sinc(), c.sin() / c);
+    /// assert!(Complex::new(1e-10, -1e-10).sinc().approx_eq(&Complex::new(1.0, 0.0), 1e-15));
     /// ```
     /// # Panics
     /// This function does not panic.
@@ -137,21 +1837,47 @@ impl Complex {
     /// This function does not abort.
     /// # Undefined Behavior
     /// This function does not cause undefined behavior.
-    pub fn exp(&self) -> Self {
-        let e = self.re.exp();
+    /// # Implementation Details
+    /// Within `1e-8` of the origin the correction `z^2 / 6` is below the rounding
+    /// error of `ONE`, so the removable singularity is filled in with `ONE`.
+    pub fn sinc(&self) -> Self {
+        if self.norm() < T::from_f64(1e-8) {
+            return Self::ONE;
+        }
+        self.sin() / *self
+    }
+
+    /// Return the hyperbolic sine of the complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert!(c.sinh().approx_eq(&Complex::new(-0.48905625904129363, 1.4031192506220405), 1e-15));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn sinh(&self) -> Self {
+        let e = self.exp();
+        let f = (-*self).exp();
         Self {
-            re: e * self.im.cos(),
-            im: e * self.im.sin(),
+            re: (e.re - f.re) / T::TWO,
+            im: (e.im - f.im) / T::TWO,
         }
     }
 
-    /// Return the complex number raised to the power of a real number.
+    /// Return the hyperbolic cosine of the complex number.
     /// # Examples
     /// ```
     /// use xcomplex::number::Complex;
-    /// use std::f64::consts::PI;
     /// let c = Complex::new(1.0, 2.0);
-    /// assert_eq!(c.powf(PI), Complex::new(-11.826467250438055, -4.138504280918663));
+    /// assert!(c.cosh().approx_eq(&Complex::new(-0.64214812471552, 1.0686074213827783), 1e-15));
+    /// assert_eq!(Complex::new(0.0, 2.0).cosh(), Complex::new(2.0_f64.cos(), 0.0));
     /// ```
     /// # Panics
     /// This function does not panic.
@@ -161,22 +1887,22 @@ impl Complex {
     /// This function does not abort.
     /// # Undefined Behavior
     /// This function does not cause undefined behavior.
-    pub fn powf(&self, n: f64) -> Self {
-        let r = self.norm();
-        let theta = self.arg();
-        let e = r.powf(n);
+    pub fn cosh(&self) -> Self {
+        let e = self.exp();
+        let f = (-*self).exp();
         Self {
-            re: e * (theta * n).cos(),
-            im: e * (theta * n).sin(),
+            re: (e.re + f.re) / T::TWO,
+            im: (e.im + f.im) / T::TWO,
         }
     }
 
-    /// Return the complex number raised to the power of an integer.
+    /// Return the hyperbolic tangent of the complex number.
     /// # Examples
     /// ```
     /// use xcomplex::number::Complex;
     /// let c = Complex::new(1.0, 2.0);
-    /// assert_eq!(c.powi(2), Complex::new(-3.0, 4.000000000000002));
+    /// assert!(c.tanh().approx_eq(&Complex::new(1.16673625724092, -0.24345820118572528), 1e-15));
+    /// assert_eq!(Complex::new(1000.0, 1.0).tanh(), Complex::new(1.0, 0.0));
     /// ```
     /// # Panics
     /// This function does not panic.
@@ -186,22 +1912,42 @@ impl Complex {
     /// This function does not abort.
     /// # Undefined Behavior
     /// This function does not cause undefined behavior.
-    pub fn powi(&self, n: i32) -> Self {
-        let r = self.norm();
-        let theta = self.arg();
-        let e = r.powi(n);
+    /// # Implementation Details
+    /// For large real parts `sinh / cosh` overflows, so the ratio is rewritten
+    /// as `(1 - exp(-2z)) / (1 + exp(-2z))`, which stays bounded.
+    pub fn tanh(&self) -> Self {
+        if self.re.abs() <= T::ONE {
+            return self.sinh() / self.cosh();
+        }
+        let s = self.re.signum();
+        let w = Complex::new(-T::TWO * s * self.re, -T::TWO * s * self.im).exp();
+        let t = (Self::ONE - w) / (Self::ONE + w);
         Self {
-            re: e * (theta * n as f64).cos(),
-            im: e * (theta * n as f64).sin(),
+            re: s * t.re,
+            im: s * t.im,
         }
     }
 
-    /// Return the natural logarithm of the complex number.
+    /// Return the principal arcsine of the complex number.
     /// # Examples
     /// ```
     /// use xcomplex::number::Complex;
     /// let c = Complex::new(1.0, 2.0);
-    /// assert_eq!(c.ln(), Complex::new(0.8047189562170503, 1.1071487177940904));
+    /// assert!(c.asin().approx_eq(&Complex::new(0.4270785863924761, 1.5285709194809982), 1e-15));
+    /// assert_eq!(Complex::new(2.0, 0.0).asin(), Complex::new(1.5707963267948966, 1.3169578969248166));
+    /// assert_eq!(Complex::new(2.0, -0.0).asin(), Complex::new(1.5707963267948966, -1.3169578969248166));
+    /// // large arguments in all four quadrants, checked against C99 `casin`
+    /// let quadrants = [
+    ///     (Complex::new(1e10, 1e10), Complex::new(0.7853981633974483, 24.065571700780374)),
+    ///     (Complex::new(-1e10, 1e10), Complex::new(-0.7853981633974483, 24.065571700780374)),
+    ///     (Complex::new(-1e10, -1e10), Complex::new(-0.7853981633974483, -24.065571700780374)),
+    ///     (Complex::new(1e10, -1e10), Complex::new(0.7853981633974483, -24.065571700780374)),
+    /// ];
+    /// for (z, w) in quadrants {
+    ///     assert!(z.asin().approx_eq(&w, 1e-15));
+    /// }
+    /// assert!(Complex::new(1e5, 3.0).asin().approx_eq(&Complex::new(1.5707663267949041, 12.206072645955174), 1e-15));
+    /// assert!(Complex::new(1e200, 1.0).asin().approx_eq(&Complex::new(1.5707963267948966, 461.2101657793691), 1e-15));
     /// ```
     /// # Panics
     /// This function does not panic.
@@ -211,19 +1957,45 @@ impl Complex {
     /// This function does not abort.
     /// # Undefined Behavior
     /// This function does not cause undefined behavior.
-    pub fn ln(&self) -> Self {
+    /// # Implementation Details
+    /// The formula `-i ln(iz + sqrt(1 - z^2))` cancels badly in whole quadrants, so
+    /// this function follows Hull, Fairgrieve and Tang: it works on `|re| + i|im|`
+    /// with the real formulas `re = asin(B)` and `im = acosh(A)`, where `A` and `B`
+    /// come from `|z + 1|` and `|z - 1|`, and copies the signs back afterwards.
+    /// Above `1e8` the asymptotic form `ln(2z)` is used so that `z^2` is never
+    /// formed. The branch cuts lie on the real axis outside `[-1, 1]`, following
+    /// C99 `casin`; the sign of a zero imaginary part selects the side of the cut.
+    pub fn asin(&self) -> Self {
+        let (x, y) = (self.re.abs(), self.im.abs());
+        let (s, acosh_a) = asin_acos_parts(x, y);
         Self {
-            re: self.norm().ln(),
-            im: self.arg(),
+            re: x.atan2(s).copysign(self.re),
+            im: acosh_a.copysign(self.im),
         }
     }
 
-    /// Return the complex number raised to the power of another complex number.
+    /// Return the principal arccosine of the complex number.
     /// # Examples
     /// ```
     /// use xcomplex::number::Complex;
     /// let c = Complex::new(1.0, 2.0);
-    /// assert_eq!(c.powc(Complex::new(2.0, 3.0)), Complex::new(-7.041080062171126, -7.259799175444256));
+    /// assert!(c.acos().approx_eq(&Complex::new(1.1437177404024206, -1.528570919480998), 1e-15));
+    /// assert_eq!(Complex::new(2.0, 0.0).acos(), Complex::new(0.0, -1.3169578969248166));
+    /// assert_eq!(Complex::new(2.0, -0.0).acos(), Complex::new(0.0, 1.3169578969248166));
+    /// // large arguments in all four quadrants, checked against C99 `cacos`
+    /// let quadrants = [
+    ///     (Complex::new(1e10, 1e10), Complex::new(0.7853981633974483, -24.065571700780374)),
+    ///     (Complex::new(-1e10, 1e10), Complex::new(2.356194490192345, -24.065571700780374)),
+    ///     (Complex::new(-1e10, -1e10), Complex::new(2.356194490192345, 24.065571700780374)),
+    ///     (Complex::new(1e10, -1e10), Complex::new(0.7853981633974483, 24.065571700780374)),
+    /// ];
+    /// for (z, w) in quadrants {
+    ///     assert!(z.acos().approx_eq(&w, 1e-15));
+    /// }
+    /// let w: Complex = Complex::new(1e5, 3.0).acos();
+    /// assert!((w.re - 2.9999999992499995e-5).abs() <= 1e-15 * 2.9999999992499995e-5);
+    /// assert!((w.im + 12.206072645955174).abs() <= 1e-15 * 12.206072645955174);
+    /// assert_eq!(Complex::new(1e200, 1.0).acos().re, 1e-200);
     /// ```
     /// # Panics
     /// This function does not panic.
@@ -233,16 +2005,27 @@ impl Complex {
     /// This function does not abort.
     /// # Undefined Behavior
     /// This function does not cause undefined behavior.
-    pub fn powc(&self, n: Self) -> Self {
-        self.ln() * n.exp()
+    /// # Implementation Details
+    /// This function shares the method of `asin`, computing `re = acos(B)` and
+    /// `im = -acosh(A)` so that small real parts near `B = 1` keep full precision.
+    /// The branch cuts lie on the real axis outside `[-1, 1]`, following C99
+    /// `cacos`.
+    pub fn acos(&self) -> Self {
+        let (x, y) = (self.re.abs(), self.im.abs());
+        let (s, acosh_a) = asin_acos_parts(x, y);
+        Self {
+            re: s.atan2(self.re),
+            im: -acosh_a.copysign(self.im),
+        }
     }
 
-    /// Return the square root of the complex number.
+    /// Return the principal arctangent of the complex number.
     /// # Examples
     /// ```
     /// use xcomplex::number::Complex;
     /// let c = Complex::new(1.0, 2.0);
-    /// assert_eq!(c.sqrt(), Complex::new(1.272019649514069, 0.7861513777574233));
+    /// assert_eq!(c.atan(), Complex::new(1.3389725222944935, 0.4023594781085251));
+    /// assert!(Complex::new(0.0, 2.0).atan().approx_eq(&Complex::new(1.5707963267948966, 0.5493061443340549), 1e-15));
     /// ```
     /// # Panics
     /// This function does not panic.
@@ -252,17 +2035,63 @@ impl Complex {
     /// This function does not abort.
     /// # Undefined Behavior
     /// This function does not cause undefined behavior.
-    pub fn sqrt(&self) -> Self {
-        let r = self.norm();
-        let theta = self.arg();
+    /// # Implementation Details
+    /// This function computes `(i / 2) (ln(1 - iz) - ln(1 + iz))`. The branch cuts
+    /// lie on the imaginary axis outside `[-i, i]`, following C99 `catan`.
+    pub fn atan(&self) -> Self {
+        let a = Complex::new(T::ONE + self.im, -self.re).ln();
+        let b = Complex::new(T::ONE - self.im, self.re).ln();
         Self {
-            re: r.sqrt() * (theta / 2.0).cos(),
-            im: r.sqrt() * (theta / 2.0).sin(),
+            re: -(a.im - b.im) / T::TWO,
+            im: (a.re - b.re) / T::TWO,
         }
     }
 }
 
-impl Add for Complex {
+/// Return `(sqrt(A^2 - x^2), acosh(A))` for `x, y >= 0`, where
+/// `A = (|z + 1| + |z - 1|) / 2`, so that `asin(z) = atan2(x, s) + i acosh(A)`.
+/// The differences `A - x` and `A - 1` are rearranged so that they never cancel.
+fn asin_acos_parts<T: Float>(x: T, y: T) -> (T, T) {
+    let half = T::ONE / T::TWO;
+    if x > T::from_f64(1e8) || y > T::from_f64(1e8) {
+        // ln(2|z|) = ln(|z| / 2) + ln(4), without overflowing in `hypot`
+        let ln_2z = (x * half).hypot(y * half).ln() + T::from_f64(4.0).ln();
+        return (y, ln_2z);
+    }
+    let r = (x + T::ONE).hypot(y);
+    let s = (x - T::ONE).hypot(y);
+    // rounding can push the mean of the two distances just below its bound of 1
+    let a = (r + s) * half;
+    let a = if a < T::ONE { T::ONE } else { a };
+    let y2 = y * y;
+    let sqrt_a2_x2 = if x <= T::ONE {
+        let a_minus_x = half * (y2 / (r + x + T::ONE) + (s + (T::ONE - x)));
+        (a_minus_x * (a + x)).sqrt()
+    } else {
+        // `A - x` is proportional to `y^2` here, so `y` is taken out of the root
+        let k = T::ONE / (r + x + T::ONE) + T::ONE / (s + (x - T::ONE));
+        y * (half * k * (a + x)).sqrt()
+    };
+    let acosh_a = if y < T::EPSILON * (x - T::ONE).abs() {
+        if x < T::ONE {
+            y / ((T::ONE - x) * (T::ONE + x)).sqrt()
+        } else {
+            ((x - T::ONE) + ((x - T::ONE) * (x + T::ONE)).sqrt()).ln_1p()
+        }
+    } else if a < T::from_f64(10.0) {
+        let a_minus_1 = if x < T::ONE {
+            half * (y2 / (r + x + T::ONE) + y2 / (s + (T::ONE - x)))
+        } else {
+            half * (y2 / (r + x + T::ONE) + (s + (x - T::ONE)))
+        };
+        (a_minus_1 + (a_minus_1 * (a + T::ONE)).sqrt()).ln_1p()
+    } else {
+        (a + (a * a - T::ONE).sqrt()).ln()
+    };
+    (sqrt_a2_x2, acosh_a)
+}
+
+impl<T: Float> Add for Complex<T> {
     type Output = Self;
     fn add(self, rhs: Self) -> Self::Output {
         Self {
@@ -272,7 +2101,7 @@ impl Add for Complex {
     }
 }
 
-impl Mul for Complex {
+impl<T: Float> Mul for Complex<T> {
     type Output = Self;
     fn mul(self, rhs: Self) -> Self::Output {
         Self {
@@ -282,7 +2111,7 @@ impl Mul for Complex {
     }
 }
 
-impl Sub for Complex {
+impl<T: Float> Sub for Complex<T> {
     type Output = Self;
     fn sub(self, rhs: Self) -> Self::Output {
         Self {
@@ -292,18 +2121,450 @@ impl Sub for Complex {
     }
 }
 
-impl Div for Complex {
+/// Divide two complex numbers using Smith's algorithm, which avoids overflow in
+/// the squared norm of the divisor.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// let c = Complex::new(1.0, 2.0);
+/// let d = Complex::new(3.0, 4.0);
+/// assert_eq!(c / d, Complex::new(0.44, 0.08));
+/// let q = Complex::new(4e200, 2e200) / Complex::new(1e200, 1e200);
+/// assert!(q.is_finite());
+/// assert!(q.approx_eq(&Complex::new(3.0, -1.0), 1e-15));
+/// let q = Complex::new(1e-200, 0.0) / Complex::new(0.0, 2e-200);
+/// assert!(q.approx_eq(&Complex::new(0.0, -0.5), 1e-15));
+/// ```
+impl<T: Float> Div for Complex<T> {
     type Output = Self;
     fn div(self, rhs: Self) -> Self::Output {
-        let d = rhs.re * rhs.re + rhs.im * rhs.im;
+        if rhs.re.abs() >= rhs.im.abs() {
+            let r = rhs.im / rhs.re;
+            let d = rhs.re + rhs.im * r;
+            Self {
+                re: (self.re + self.im * r) / d,
+                im: (self.im - self.re * r) / d,
+            }
+        } else {
+            let r = rhs.re / rhs.im;
+            let d = rhs.re * r + rhs.im;
+            Self {
+                re: (self.re * r + self.im) / d,
+                im: (self.im * r - self.re) / d,
+            }
+        }
+    }
+}
+
+/// Return the Gaussian-integer style remainder `a - b * round(a / b)`.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// let a = Complex::new(5.0, 3.0);
+/// let b = Complex::new(2.0, 1.0);
+/// assert_eq!(a % b, Complex::new(-1.0, 0.0));
+/// assert!((a % b).norm() < b.norm());
+/// // the quotients 2.5 and 3.5 are ties, rounded to even like `Complex::round`
+/// let two = Complex::new(2.0, 0.0);
+/// assert_eq!(Complex::new(5.0, 0.0) % two, Complex::new(1.0, 0.0));
+/// assert_eq!(Complex::new(7.0, 0.0) % two, Complex::new(-1.0, 0.0));
+/// ```
+impl<T: Float> Rem for Complex<T> {
+    type Output = Self;
+    fn rem(self, rhs: Self) -> Self::Output {
+        self - rhs * (self / rhs).round()
+    }
+}
+
+/// Add two borrowed complex numbers.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// let c = Complex::new(1.0, 2.0);
+/// let d = Complex::new(3.0, 4.0);
+/// assert_eq!(&c + &d, c + d);
+/// assert_eq!(&c - &d, c - d);
+/// assert_eq!(&c * &d, c * d);
+/// assert_eq!(&c / &d, c / d);
+/// ```
+impl<T: Float> Add<&Complex<T>> for &Complex<T> {
+    type Output = Complex<T>;
+    fn add(self, rhs: &Complex<T>) -> Self::Output {
+        *self + *rhs
+    }
+}
+
+impl<T: Float> Mul<&Complex<T>> for &Complex<T> {
+    type Output = Complex<T>;
+    fn mul(self, rhs: &Complex<T>) -> Self::Output {
+        *self * *rhs
+    }
+}
+
+impl<T: Float> Sub<&Complex<T>> for &Complex<T> {
+    type Output = Complex<T>;
+    fn sub(self, rhs: &Complex<T>) -> Self::Output {
+        *self - *rhs
+    }
+}
+
+impl<T: Float> Div<&Complex<T>> for &Complex<T> {
+    type Output = Complex<T>;
+    fn div(self, rhs: &Complex<T>) -> Self::Output {
+        *self / *rhs
+    }
+}
+
+/// Add a real number to a complex number.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// let c = Complex::new(1.0, 2.0);
+/// assert_eq!(c + 2.0, Complex::new(3.0, 2.0));
+/// assert_eq!(2.0 + c, Complex::new(3.0, 2.0));
+/// ```
+impl<T: Float> Add<T> for Complex<T> {
+    type Output = Self;
+    fn add(self, rhs: T) -> Self::Output {
+        Self {
+            re: self.re + rhs,
+            im: self.im,
+        }
+    }
+}
+
+/// Subtract a real number from a complex number, or a complex number from a real number.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// let c = Complex::new(1.0, 2.0);
+/// assert_eq!(c - 2.0, Complex::new(-1.0, 2.0));
+/// assert_eq!(2.0 - c, Complex::new(1.0, -2.0));
+/// ```
+impl<T: Float> Sub<T> for Complex<T> {
+    type Output = Self;
+    fn sub(self, rhs: T) -> Self::Output {
+        Self {
+            re: self.re - rhs,
+            im: self.im,
+        }
+    }
+}
+
+/// Multiply a complex number by a real number.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// let c = Complex::new(1.0, 2.0);
+/// assert_eq!(c * 2.0, Complex::new(2.0, 4.0));
+/// assert_eq!(2.0 * c, Complex::new(2.0, 4.0));
+/// ```
+impl<T: Float> Mul<T> for Complex<T> {
+    type Output = Self;
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            re: self.re * rhs,
+            im: self.im * rhs,
+        }
+    }
+}
+
+/// Divide a complex number by a real number, or a real number by a complex number.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// let c = Complex::new(1.0, 2.0);
+/// assert_eq!(c / 2.0, Complex::new(0.5, 1.0));
+/// assert_eq!(5.0 / c, Complex::new(1.0, -2.0));
+/// ```
+impl<T: Float> Div<T> for Complex<T> {
+    type Output = Self;
+    fn div(self, rhs: T) -> Self::Output {
+        Self {
+            re: self.re / rhs,
+            im: self.im / rhs,
+        }
+    }
+}
+
+macro_rules! impl_scalar_lhs {
+    ($t:ident) => {
+        impl Add<Complex<$t>> for $t {
+            type Output = Complex<$t>;
+            fn add(self, rhs: Complex<$t>) -> Self::Output {
+                rhs + self
+            }
+        }
+
+        impl Sub<Complex<$t>> for $t {
+            type Output = Complex<$t>;
+            fn sub(self, rhs: Complex<$t>) -> Self::Output {
+                Complex {
+                    re: self - rhs.re,
+                    im: -rhs.im,
+                }
+            }
+        }
+
+        impl Mul<Complex<$t>> for $t {
+            type Output = Complex<$t>;
+            fn mul(self, rhs: Complex<$t>) -> Self::Output {
+                rhs * self
+            }
+        }
+
+        impl Div<Complex<$t>> for $t {
+            type Output = Complex<$t>;
+            fn div(self, rhs: Complex<$t>) -> Self::Output {
+                Complex::new(self, 0.0) / rhs
+            }
+        }
+    };
+}
+
+impl_scalar_lhs!(f32);
+impl_scalar_lhs!(f64);
+
+/// Negate a complex number.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// let c = Complex::new(1.0, -2.0);
+/// assert_eq!(-c, Complex::new(-1.0, 2.0));
+/// assert_eq!(-&c, Complex::new(-1.0, 2.0));
+/// assert_eq!(c + (-c), Complex::new(0.0, 0.0));
+/// ```
+impl<T: Float> Neg for Complex<T> {
+    type Output = Self;
+    fn neg(self) -> Self::Output {
         Self {
-            re: (self.re * rhs.re + self.im * rhs.im) / d,
-            im: (self.im * rhs.re - self.re * rhs.im) / d,
+            re: -self.re,
+            im: -self.im,
         }
     }
 }
 
-impl PartialEq for Complex {
+impl<T: Float> Neg for &Complex<T> {
+    type Output = Complex<T>;
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
+
+/// Add a complex number in place.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// let mut a = Complex::new(0.0, 0.0);
+/// let mut b = Complex::new(0.0, 0.0);
+/// for k in 0..10 {
+///     let z = Complex::new(k as f64 * 0.1, 1.0 / (k as f64 + 1.0));
+///     a += z;
+///     b = b + z;
+/// }
+/// assert_eq!(a, b);
+/// ```
+impl<T: Float> AddAssign for Complex<T> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+/// Subtract a complex number in place.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// let mut c = Complex::new(1.0, 2.0);
+/// c -= Complex::new(3.0, 4.0);
+/// assert_eq!(c, Complex::new(1.0, 2.0) - Complex::new(3.0, 4.0));
+/// ```
+impl<T: Float> SubAssign for Complex<T> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+/// Multiply by a complex number in place.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// let mut c = Complex::new(1.0, 2.0);
+/// c *= Complex::new(3.0, 4.0);
+/// assert_eq!(c, Complex::new(1.0, 2.0) * Complex::new(3.0, 4.0));
+/// ```
+impl<T: Float> MulAssign for Complex<T> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+/// Divide by a complex number in place.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// let mut c = Complex::new(1.0, 2.0);
+/// c /= Complex::new(3.0, 4.0);
+/// assert_eq!(c, Complex::new(1.0, 2.0) / Complex::new(3.0, 4.0));
+/// ```
+impl<T: Float> DivAssign for Complex<T> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs;
+    }
+}
+
+/// Convert a real number into a complex number with zero imaginary part.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// assert_eq!(Complex::from(5.0), Complex::new(5.0, 0.0));
+/// let c: Complex = 3.0.into();
+/// assert_eq!(c, Complex::new(3.0, 0.0));
+/// ```
+impl<T: Float> From<T> for Complex<T> {
+    fn from(re: T) -> Self {
+        Self::new(re, T::ZERO)
+    }
+}
+
+/// Convert a `(re, im)` pair into a complex number.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// assert_eq!(Complex::from((1.0, 2.0)), Complex::new(1.0, 2.0));
+/// let c: Complex = (3.0, -4.0).into();
+/// assert_eq!(c, Complex::new(3.0, -4.0));
+/// ```
+impl<T: Float> From<(T, T)> for Complex<T> {
+    fn from((re, im): (T, T)) -> Self {
+        Self::new(re, im)
+    }
+}
+
+/// Convert an integer into a complex number with zero imaginary part.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// let c: Complex = Complex::from(-7);
+/// assert_eq!(c, Complex::new(-7.0, 0.0));
+/// let d: Complex = 2.into();
+/// assert_eq!(d, Complex::new(2.0, 0.0));
+/// ```
+impl<T: Float> From<i32> for Complex<T> {
+    fn from(re: i32) -> Self {
+        Self::new(T::from_f64(re.into()), T::ZERO)
+    }
+}
+
+/// Return the complex number `0 + 0i`.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// let c: Complex = Complex::default();
+/// assert_eq!(c, Complex::ZERO);
+/// let mut v = vec![Complex::new(1.0, 2.0)];
+/// v.resize_with(3, Complex::default);
+/// assert_eq!(v[2], Complex::new(0.0, 0.0));
+/// ```
+impl<T: Float> Default for Complex<T> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+/// Implement `num_traits::Zero` with the `num-traits` feature.
+/// # Examples
+/// ```
+/// # #[cfg(feature = "num-traits")] {
+/// use num_traits::Zero;
+/// use xcomplex::number::Complex;
+/// fn total<T: Zero + Copy>(xs: &[T]) -> T {
+///     xs.iter().fold(T::zero(), |acc, x| acc + *x)
+/// }
+/// let c: Complex = Complex::zero();
+/// assert_eq!(c, Complex::new(0.0, 0.0));
+/// assert!(c.is_zero());
+/// assert!(!Complex::new(0.0, 1e-300).is_zero());
+/// assert_eq!(total::<Complex>(&[]), Complex::ZERO);
+/// # }
+/// ```
+#[cfg(feature = "num-traits")]
+impl<T: Float> num_traits::Zero for Complex<T> {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+    fn is_zero(&self) -> bool {
+        self.re == T::ZERO && self.im == T::ZERO
+    }
+}
+
+/// Implement `num_traits::One` with the `num-traits` feature.
+/// # Examples
+/// ```
+/// # #[cfg(feature = "num-traits")] {
+/// use num_traits::One;
+/// use xcomplex::number::Complex;
+/// let c: Complex = Complex::one();
+/// assert_eq!(c, Complex::new(1.0, 0.0));
+/// assert_eq!(Complex::new(1.0, 2.0) * Complex::one(), Complex::new(1.0, 2.0));
+/// # }
+/// ```
+#[cfg(feature = "num-traits")]
+impl<T: Float> num_traits::One for Complex<T> {
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+/// Sum an iterator of complex numbers, starting from `ZERO`.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// let v = vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0), Complex::new(-5.0, 0.5)];
+/// let total: Complex = v.iter().sum();
+/// assert_eq!(total, v.iter().fold(Complex::ZERO, |acc, z| acc + *z));
+/// assert_eq!(v.into_iter().sum::<Complex>(), Complex::new(-1.0, 6.5));
+/// ```
+impl<T: Float> Sum for Complex<T> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, z| acc + z)
+    }
+}
+
+impl<'a, T: Float> Sum<&'a Complex<T>> for Complex<T> {
+    fn sum<I: Iterator<Item = &'a Complex<T>>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Multiply an iterator of complex numbers, starting from `ONE`.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// let v = vec![Complex::new(1.0, 2.0), Complex::new(3.0, 4.0), Complex::new(0.0, 1.0)];
+/// let total: Complex = v.iter().product();
+/// assert_eq!(total, v.iter().fold(Complex::ONE, |acc, z| acc * *z));
+/// assert_eq!(v.into_iter().product::<Complex>(), Complex::new(-10.0, -5.0));
+/// ```
+impl<T: Float> Product for Complex<T> {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, z| acc * z)
+    }
+}
+
+impl<'a, T: Float> Product<&'a Complex<T>> for Complex<T> {
+    fn product<I: Iterator<Item = &'a Complex<T>>>(iter: I) -> Self {
+        iter.copied().product()
+    }
+}
+
+/// Compare two complex numbers for exact equality.
+///
+/// Both components are compared with `==`, so results that differ only by
+/// rounding error compare unequal. Use [`Complex::approx_eq`] to compare
+/// computed values.
+// `ne` is kept from the original hand-written impl rather than derived from `eq`.
+#[allow(clippy::partialeq_ne_impl)]
+impl<T: Float> PartialEq for Complex<T> {
     fn eq(&self, other: &Self) -> bool {
         self.re == other.re && self.im == other.im
     }
@@ -311,3 +2572,180 @@ impl PartialEq for Complex {
         self.re != other.re || self.im != other.im
     }
 }
+
+/// Hash a complex number by the bit patterns of its components.
+///
+/// Signed zeros are hashed alike since they compare equal. As NaN never
+/// compares equal to itself, keys with a NaN component cannot be looked up.
+/// # Examples
+/// ```
+/// use std::collections::HashMap;
+/// use xcomplex::number::Complex;
+/// let mut cache = HashMap::new();
+/// for k in 0..4 {
+///     let z = Complex::new(k as f64, -(k as f64) / 2.0);
+///     cache.insert(z, z.exp());
+/// }
+/// assert_eq!(cache.len(), 4);
+/// assert_eq!(cache[&Complex::new(1.0, -0.5)], Complex::new(1.0, -0.5).exp());
+/// assert!(cache.contains_key(&Complex::new(0.0, -0.0)));
+/// assert!(!cache.contains_key(&Complex::new(5.0, 0.0)));
+/// ```
+impl<T: Float> Hash for Complex<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let bits = |x: T| if x == T::ZERO { 0 } else { x.to_bits() };
+        bits(self.re).hash(state);
+        bits(self.im).hash(state);
+    }
+}
+
+/// Mark complex equality as total so complex numbers can key hash maps.
+///
+/// This does not hold for components that are NaN.
+impl<T: Float> Eq for Complex<T> {}
+
+/// Format a complex number as `a+bi`.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// assert_eq!(Complex::new(1.0, 2.0).to_string(), "1+2i");
+/// assert_eq!(Complex::new(1.0, -2.0).to_string(), "1-2i");
+/// assert_eq!(Complex::new(1.5, 0.0).to_string(), "1.5");
+/// assert_eq!(format!("{:.3}", Complex::new(1.0, -2.0)), "1.000-2.000i");
+/// ```
+impl<T: Float + fmt::Display> fmt::Display for Complex<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = f.precision();
+        let part = |f: &mut fmt::Formatter, x: T| match precision {
+            Some(p) => write!(f, "{:.*}", p, x),
+            None => write!(f, "{}", x),
+        };
+        part(f, self.re)?;
+        if self.im == T::ZERO {
+            return Ok(());
+        }
+        if self.im < T::ZERO {
+            write!(f, "-")?;
+        } else {
+            write!(f, "+")?;
+        }
+        part(f, self.im.abs())?;
+        write!(f, "i")
+    }
+}
+
+/// An error returned when parsing a complex number fails.
+/// # Examples
+/// ```
+/// use xcomplex::number::{Complex, ParseComplexError};
+/// assert_eq!("".parse::<Complex>(), Err(ParseComplexError::Empty));
+/// assert_eq!("1+2".parse::<Complex>(), Err(ParseComplexError::MissingImaginaryUnit));
+/// assert!(matches!("1+xi".parse::<Complex>(), Err(ParseComplexError::InvalidFloat(_))));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseComplexError {
+    /// The input is empty.
+    Empty,
+    /// The imaginary part does not end with the imaginary unit `i` or `j`.
+    MissingImaginaryUnit,
+    /// A component is not a valid float.
+    InvalidFloat(ParseFloatError),
+}
+
+impl fmt::Display for ParseComplexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParseComplexError::Empty => write!(f, "cannot parse complex number from empty string"),
+            ParseComplexError::MissingImaginaryUnit => {
+                write!(f, "imaginary part is missing the imaginary unit")
+            }
+            ParseComplexError::InvalidFloat(e) => write!(f, "invalid float component: {}", e),
+        }
+    }
+}
+
+impl Error for ParseComplexError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseComplexError::InvalidFloat(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseFloatError> for ParseComplexError {
+    fn from(e: ParseFloatError) -> Self {
+        ParseComplexError::InvalidFloat(e)
+    }
+}
+
+/// Parse a complex number of the form `a+bi`, `a`, or `bi`.
+///
+/// The accepted grammar is:
+///
+/// ```text
+/// complex := real | imag | real ("+" | "-") imag
+/// imag    := [sign] [float] unit
+/// unit    := "i" | "j"
+/// ```
+///
+/// where `real` and `float` are anything `T::from_str` accepts, and a missing
+/// coefficient means `1`, so `i`, `-j` and `1+i` are valid. Whitespace is allowed
+/// around the whole input and around the separating sign.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// assert_eq!("1+2i".parse::<Complex>(), Ok(Complex::new(1.0, 2.0)));
+/// assert_eq!("-3.5-4i".parse::<Complex>(), Ok(Complex::new(-3.5, -4.0)));
+/// assert_eq!(" 1 + 2i ".parse::<Complex>(), Ok(Complex::new(1.0, 2.0)));
+/// assert_eq!("1e-3 - 2.5e2i".parse::<Complex>(), Ok(Complex::new(1e-3, -2.5e2)));
+/// assert_eq!("5".parse::<Complex>(), Ok(Complex::new(5.0, 0.0)));
+/// assert_eq!("-2i".parse::<Complex>(), Ok(Complex::new(0.0, -2.0)));
+/// assert_eq!("3+4j".parse::<Complex>(), Ok(Complex::new(3.0, 4.0)));
+/// assert_eq!("2i".parse::<Complex>(), Ok(Complex::new(0.0, 2.0)));
+/// assert_eq!("i".parse::<Complex>(), Ok(Complex::new(0.0, 1.0)));
+/// assert_eq!("j".parse::<Complex>(), Ok(Complex::new(0.0, 1.0)));
+/// assert_eq!("-i".parse::<Complex>(), Ok(Complex::new(0.0, -1.0)));
+/// assert_eq!("1 - j".parse::<Complex>(), Ok(Complex::new(1.0, -1.0)));
+/// assert!("".parse::<Complex>().is_err());
+/// assert!("1+2".parse::<Complex>().is_err());
+/// assert!("abc".parse::<Complex>().is_err());
+/// assert!("1++2i".parse::<Complex>().is_err());
+/// assert!("1 2i".parse::<Complex>().is_err());
+/// assert!("2ij".parse::<Complex>().is_err());
+/// ```
+impl<T: Float + FromStr<Err = ParseFloatError>> FromStr for Complex<T> {
+    type Err = ParseComplexError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseComplexError::Empty);
+        }
+        // The separator is the last sign that neither leads the string nor
+        // belongs to an exponent such as `1e-3`.
+        let split = s
+            .char_indices()
+            .rev()
+            .find(|&(i, c)| (c == '+' || c == '-') && i > 0 && !s[..i].ends_with(['e', 'E']));
+        match split {
+            Some((i, sign)) => {
+                let re = s[..i].trim().parse()?;
+                let im: T = parse_imaginary(s[i + 1..].trim())?;
+                Ok(Complex::new(re, if sign == '-' { -im } else { im }))
+            }
+            None if s.ends_with(['i', 'j']) => Ok(Complex::new(T::ZERO, parse_imaginary(s)?)),
+            None => Ok(Complex::new(s.parse()?, T::ZERO)),
+        }
+    }
+}
+
+fn parse_imaginary<T: Float + FromStr<Err = ParseFloatError>>(
+    s: &str,
+) -> Result<T, ParseComplexError> {
+    match s.strip_suffix(['i', 'j']) {
+        Some("") | Some("+") => Ok(T::ONE),
+        Some("-") => Ok(-T::ONE),
+        Some(coeff) => Ok(coeff.parse()?),
+        None => Err(ParseComplexError::MissingImaginaryUnit),
+    }
+}
@@ -0,0 +1,77 @@
+//! phase
+//!
+//! This module contains helpers for working with sequences of phase angles, such
+//! as the arguments of complex numbers sampled along a path.
+//!
+//! # Examples
+//!
+//! ```
+//! use xcomplex::number::Complex;
+//! use xcomplex::phase::unwrap_phase;
+//!
+//! let path: Vec<Complex> = (0..40).map(|k| Complex::cis(0.25 * k as f64)).collect();
+//! let args: Vec<f64> = path.iter().map(|z| z.arg()).collect();
+//! let phase = unwrap_phase(&args);
+//! assert!((phase[39] - 0.25 * 39.0).abs() < 1e-12);
+//! ```
+//!
+//! # References
+//!
+//! * [Phase unwrapping](https://en.wikipedia.org/wiki/Instantaneous_phase_and_frequency)
+
+use alloc::vec::Vec;
+
+use crate::float::Float;
+
+/// Remove jumps larger than `PI` between consecutive angles by adding multiples of `2 PI`.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// use xcomplex::phase::unwrap_phase;
+/// let args: Vec<f64> = (0..12).map(|k| Complex::cis(0.6 * k as f64).arg()).collect();
+/// assert!(args.windows(2).any(|w| w[1] < w[0]));
+/// let phase = unwrap_phase(&args);
+/// assert_eq!(phase.len(), args.len());
+/// for (k, w) in phase.windows(2).enumerate() {
+///     assert!(w[1] > w[0]);
+///     assert!((w[1] - w[0] - 0.6).abs() < 1e-12, "jump at {}", k);
+/// }
+/// assert_eq!(phase[0], args[0]);
+/// assert!(unwrap_phase::<f64>(&[]).is_empty());
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// This function follows numpy's `unwrap`: every difference between neighbours
+/// whose magnitude exceeds `PI` is shifted into `[-PI, PI]` and the correction is
+/// accumulated. A difference that lands exactly on `±PI` keeps the sign of the
+/// original difference.
+pub fn unwrap_phase<T: Float>(args: &[T]) -> Vec<T> {
+    let two_pi = T::TWO * T::PI;
+    let mut out = Vec::with_capacity(args.len());
+    let mut correction = T::ZERO;
+    let mut prev = match args.first() {
+        Some(&first) => first,
+        None => return out,
+    };
+    out.push(prev);
+    for &x in &args[1..] {
+        let d = x - prev;
+        if d.abs() >= T::PI {
+            let mut wrapped = d - two_pi * (d / two_pi).round();
+            if wrapped.abs() == T::PI {
+                wrapped = T::PI * d.signum();
+            }
+            correction = correction + wrapped - d;
+        }
+        out.push(x + correction);
+        prev = x;
+    }
+    out
+}
@@ -0,0 +1,206 @@
+//! polynomial
+//!
+//! This module contains functions operating on polynomials with complex coefficients.
+//! Coefficients are always given highest degree first.
+//!
+//! # Examples
+//!
+//! ```
+//! use xcomplex::number::Complex;
+//! use xcomplex::poly::roots;
+//!
+//! // x^2 + 1
+//! let r = roots(&[Complex::new(1.0, 0.0), Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)]);
+//! assert_eq!(r.len(), 2);
+//! ```
+//!
+//! # References
+//!
+//! * [Geometric series](https://en.wikipedia.org/wiki/Geometric_series)
+//! * [Quadratic formula](https://en.wikipedia.org/wiki/Quadratic_formula)
+//! * [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+//! * [Durand-Kerner method](https://en.wikipedia.org/wiki/Durand%E2%80%93Kerner_method)
+
+use alloc::vec::Vec;
+
+use crate::float::Float;
+use crate::number::Complex;
+
+const MAX_ITERATIONS: usize = 1000;
+
+/// Evaluate the polynomial with the given coefficients, highest degree first, at `x`.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// use xcomplex::poly::eval;
+/// let p = [Complex::new(1.0, 0.0), Complex::new(0.0, 0.0), Complex::new(1.0, 0.0)];
+/// assert_eq!(eval(&p, Complex::new(0.0, 1.0)), Complex::new(0.0, 0.0));
+/// assert_eq!(eval(&p, Complex::new(2.0, 0.0)), Complex::new(5.0, 0.0));
+/// assert_eq!(eval(&[], Complex::new(2.0, 0.0)), Complex::new(0.0, 0.0));
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// This function uses Horner's method with fused multiply-adds. The empty
+/// polynomial evaluates to `ZERO`.
+pub fn eval<T: Float>(coeffs: &[Complex<T>], x: Complex<T>) -> Complex<T> {
+    coeffs
+        .iter()
+        .fold(Complex::ZERO, |acc, c| acc.mul_add(x, *c))
+}
+
+/// Return all roots of the polynomial with the given coefficients, highest degree first.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// use xcomplex::poly::roots;
+/// let one = Complex::new(1.0, 0.0);
+/// let zero = Complex::new(0.0, 0.0);
+/// let r = roots(&[one, zero, -one]);
+/// assert!(r.iter().any(|z| z.approx_eq(&Complex::new(1.0, 0.0), 1e-9)));
+/// assert!(r.iter().any(|z| z.approx_eq(&Complex::new(-1.0, 0.0), 1e-9)));
+/// let r = roots(&[one, zero, one]);
+/// assert!(r.iter().any(|z| z.approx_eq(&Complex::new(0.0, 1.0), 1e-9)));
+/// assert!(r.iter().any(|z| z.approx_eq(&Complex::new(0.0, -1.0), 1e-9)));
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// This function runs the Durand-Kerner iteration until every update falls below
+/// `1e-12`, or gives up after a fixed number of iterations. Leading zero
+/// coefficients are ignored, so the result has one root per degree of the
+/// polynomial and is empty for constant polynomials.
+pub fn roots<T: Float>(coeffs: &[Complex<T>]) -> Vec<Complex<T>> {
+    let start = match coeffs.iter().position(|c| *c != Complex::ZERO) {
+        Some(i) => i,
+        None => return Vec::new(),
+    };
+    let lead = coeffs[start];
+    let monic: Vec<Complex<T>> = coeffs[start..].iter().map(|c| *c / lead).collect();
+    let degree = monic.len() - 1;
+
+    let seed = Complex::new(T::from_f64(0.4), T::from_f64(0.9));
+    let mut z: Vec<Complex<T>> = Vec::with_capacity(degree);
+    let mut w = Complex::ONE;
+    for _ in 0..degree {
+        z.push(w);
+        w *= seed;
+    }
+
+    let tolerance = T::from_f64(1e-12);
+    for _ in 0..MAX_ITERATIONS {
+        let mut delta = T::ZERO;
+        for i in 0..degree {
+            let p = eval(&monic, z[i]);
+            let d: Complex<T> = (0..degree)
+                .filter(|&j| j != i)
+                .map(|j| z[i] - z[j])
+                .product();
+            let step = p / d;
+            z[i] -= step;
+            if step.norm() > delta {
+                delta = step.norm();
+            }
+        }
+        if delta < tolerance {
+            break;
+        }
+    }
+    z
+}
+
+/// Return both roots of the quadratic equation `a * x^2 + b * x + c = 0`.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// use xcomplex::poly::solve_quadratic;
+/// let one = Complex::new(1.0, 0.0);
+/// let zero = Complex::new(0.0, 0.0);
+/// let (x1, x2) = solve_quadratic(one, zero, one);
+/// assert!(x1.approx_eq(&Complex::new(0.0, 1.0), 1e-12));
+/// assert!(x2.approx_eq(&Complex::new(0.0, -1.0), 1e-12));
+/// // i (x - (1 + i)) (x - (2 - i)) = i x^2 - 3i x + (-1 + 3i)
+/// let (x1, x2) = solve_quadratic(Complex::new(0.0, 1.0), Complex::new(0.0, -3.0), Complex::new(-1.0, 3.0));
+/// assert!(x1.approx_eq(&Complex::new(2.0, -1.0), 1e-12));
+/// assert!(x2.approx_eq(&Complex::new(1.0, 1.0), 1e-12));
+/// // linear: 2x + 4 = 0
+/// let (x1, x2) = solve_quadratic(zero, Complex::new(2.0, 0.0), Complex::new(4.0, 0.0));
+/// assert_eq!(x1, Complex::new(-2.0, 0.0));
+/// assert_eq!(x2, x1);
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// This function uses the quadratic formula with the principal square root of the
+/// discriminant, returning `(-b + sqrt(d)) / 2a` first. When `a` is zero the
+/// equation is linear and its single root `-c / b` is returned twice; if `b` is
+/// zero as well the result is not finite.
+pub fn solve_quadratic<T: Float>(
+    a: Complex<T>,
+    b: Complex<T>,
+    c: Complex<T>,
+) -> (Complex<T>, Complex<T>) {
+    if a == Complex::ZERO {
+        let x = -c / b;
+        return (x, x);
+    }
+    let d = (b * b - a * c * T::from_f64(4.0)).sqrt();
+    let two_a = a * T::TWO;
+    ((-b + d) / two_a, (-b - d) / two_a)
+}
+
+/// Return the sum `1 + z + z^2 + ... + z^(n - 1)` of the first `n` powers of `z`.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// use xcomplex::poly::geometric_sum;
+/// let z = Complex::new(0.5, 0.5);
+/// let mut expected = Complex::new(0.0, 0.0);
+/// let mut w = Complex::new(1.0, 0.0);
+/// for _ in 0..10 {
+///     expected += w;
+///     w *= z;
+/// }
+/// assert!(geometric_sum(z, 10).approx_eq(&expected, 1e-12));
+/// assert_eq!(geometric_sum(Complex::new(1.0, 0.0), 7), Complex::new(7.0, 0.0));
+/// assert_eq!(geometric_sum(z, 0), Complex::new(0.0, 0.0));
+/// // integer powers are exact, also on the negative real axis
+/// assert_eq!(geometric_sum(Complex::new(-1.0, 0.0), 3), Complex::new(1.0, 0.0));
+/// assert_eq!(geometric_sum(Complex::new(0.0, 1.0), 4), Complex::new(0.0, 0.0));
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// This function uses the closed form `(z^n - 1) / (z - 1)`, with `z^n` from
+/// `powu`. When `z` is within `1e-12` of `ONE` the closed form cancels
+/// catastrophically, so the limit `n` is returned instead.
+pub fn geometric_sum<T: Float>(z: Complex<T>, n: u32) -> Complex<T> {
+    if z.approx_eq(&Complex::ONE, T::from_f64(1e-12)) {
+        return Complex::new(T::from_f64(n as f64), T::ZERO);
+    }
+    (z.powu(n) - Complex::ONE) / (z - Complex::ONE)
+}
@@ -0,0 +1,14 @@
+//! prelude
+//!
+//! This module re-exports the most commonly used items, so a single glob import
+//! is enough to start writing complex arithmetic.
+//!
+//! # Examples
+//!
+//! ```
+//! use xcomplex::prelude::*;
+//!
+//! assert_eq!(3.0 + 4.0 * i(), Complex::new(3.0, 4.0));
+//! ```
+
+pub use crate::number::{i, Complex};
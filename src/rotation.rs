@@ -0,0 +1,188 @@
+//! rotation
+//!
+//! This module contains an accumulator that tracks the total angle of a sequence
+//! of rotations without the `2 PI` jumps of `arg`.
+//!
+//! # Examples
+//!
+//! ```
+//! use xcomplex::number::Complex;
+//! use xcomplex::rotation::Rotation;
+//!
+//! let mut rotation = Rotation::new();
+//! for _ in 0..8 {
+//!     rotation.apply(Complex::new(0.0, 1.0));
+//! }
+//! assert_eq!(rotation.winding(), 2);
+//! assert!((rotation.angle() - 4.0 * std::f64::consts::PI).abs() < 1e-12);
+//! ```
+//!
+//! # References
+//!
+//! * [Winding number](https://en.wikipedia.org/wiki/Winding_number)
+
+use crate::float::Float;
+use crate::number::Complex;
+
+/// An accumulated rotation together with the number of full turns it has made.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// use xcomplex::rotation::Rotation;
+/// let mut rotation: Rotation = Rotation::new();
+/// rotation.apply(Complex::from_polar_deg(1.0, 120.0));
+/// rotation.apply(Complex::from_polar_deg(1.0, 90.0));
+/// rotation.apply(Complex::from_polar_deg(1.0, -60.0));
+/// assert!((rotation.angle().to_degrees() - 150.0).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Rotation<T = f64> {
+    current: Complex<T>,
+    winding: i64,
+}
+
+impl<T: Float> Rotation<T> {
+    /// Create a rotation with zero total angle.
+    /// # Examples
+    /// ```
+    /// use xcomplex::rotation::Rotation;
+    /// let rotation: Rotation = Rotation::new();
+    /// assert_eq!(rotation.angle(), 0.0);
+    /// assert_eq!(rotation.winding(), 0);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn new() -> Self {
+        Self {
+            current: Complex::ONE,
+            winding: 0,
+        }
+    }
+
+    /// Rotate by the argument of `delta`.
+    /// # Examples
+    /// ```
+    /// use std::f64::consts::PI;
+    /// use xcomplex::number::Complex;
+    /// use xcomplex::rotation::Rotation;
+    /// let mut rotation = Rotation::new();
+    /// for _ in 0..12 {
+    ///     rotation.apply(Complex::from_polar_deg(1.0, 30.0));
+    /// }
+    /// assert!((rotation.angle() - 2.0 * PI).abs() < 1e-12);
+    /// assert_eq!(rotation.winding(), 1);
+    /// for _ in 0..18 {
+    ///     rotation.apply(Complex::from_polar_deg(2.0, -30.0));
+    /// }
+    /// assert!((rotation.angle() + PI).abs() < 1e-12);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// Only the direction of `delta` matters: the accumulated orientation is kept
+    /// at unit norm, so magnitudes neither build up nor drift. Each step turns by
+    /// `delta.arg()`, which lies in `(-PI, PI]`, so a crossing of the negative real
+    /// axis shows up as the argument moving against the direction of the step,
+    /// and is counted as a full turn. A zero `delta` is ignored.
+    pub fn apply(&mut self, delta: Complex<T>) {
+        if delta == Complex::ZERO {
+            return;
+        }
+        let step = delta.arg();
+        let before = self.current.arg();
+        self.current = (self.current * delta.signum()).signum();
+        let after = self.current.arg();
+        if step > T::ZERO && after < before {
+            self.winding += 1;
+        } else if step < T::ZERO && after > before {
+            self.winding -= 1;
+        }
+    }
+
+    /// Return the current orientation as a unit complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// use xcomplex::rotation::Rotation;
+    /// let mut rotation = Rotation::new();
+    /// rotation.apply(Complex::new(0.0, 3.0));
+    /// assert_eq!(rotation.current(), Complex::new(0.0, 1.0));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn current(&self) -> Complex<T> {
+        self.current
+    }
+
+    /// Return the number of times the rotation has crossed the negative real axis,
+    /// counted positively for counterclockwise crossings.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// use xcomplex::rotation::Rotation;
+    /// let mut rotation = Rotation::new();
+    /// for _ in 0..6 {
+    ///     rotation.apply(Complex::from_polar_deg(1.0, -100.0));
+    /// }
+    /// assert_eq!(rotation.winding(), -2);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn winding(&self) -> i64 {
+        self.winding
+    }
+
+    /// Return the total angle turned so far, in radians.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// use xcomplex::rotation::Rotation;
+    /// let mut rotation: Rotation = Rotation::new();
+    /// rotation.apply(Complex::from_polar_deg(1.0, 170.0));
+    /// rotation.apply(Complex::from_polar_deg(1.0, 20.0));
+    /// assert!((rotation.angle().to_degrees() - 190.0).abs() < 1e-9);
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// The angle is `2 PI * winding() + current().arg()`.
+    pub fn angle(&self) -> T {
+        T::TWO * T::PI * T::from_f64(self.winding as f64) + self.current.arg()
+    }
+}
+
+impl<T: Float> Default for Rotation<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
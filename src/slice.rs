@@ -0,0 +1,407 @@
+//! slice
+//!
+//! This module contains helper functions operating on slices of complex numbers.
+//!
+//! # Examples
+//!
+//! ```
+//! use xcomplex::number::Complex;
+//! use xcomplex::slice::dot;
+//!
+//! let v = [Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)];
+//! assert_eq!(dot(&v, &v), Complex::new(30.0, 0.0));
+//! ```
+
+use alloc::vec::Vec;
+
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+
+use crate::float::Float;
+use crate::number::Complex;
+
+/// Return the Hermitian inner product `sum(a[i].conj() * b[i])` of two slices.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// use xcomplex::slice::dot;
+/// let a = [Complex::new(1.0, 2.0), Complex::new(0.0, -1.0), Complex::new(3.0, 0.0)];
+/// let b = [Complex::new(2.0, 1.0), Complex::new(1.0, 1.0), Complex::new(-1.0, 4.0)];
+/// // (1-2i)(2+i) + (i)(1+i) + 3(-1+4i) = (4-3i) + (-1+i) + (-3+12i)
+/// assert_eq!(dot(&a, &b), Complex::new(0.0, 10.0));
+/// let v = dot(&a, &a);
+/// assert_eq!(v.im, 0.0);
+/// assert_eq!(v.re, a.iter().map(|z| z.norm_sqr()).sum::<f64>());
+/// ```
+/// ```should_panic
+/// use xcomplex::number::Complex;
+/// use xcomplex::slice::dot;
+/// dot(&[Complex::new(1.0, 0.0)], &[]);
+/// ```
+/// # Panics
+/// This function panics if the slices have different lengths.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+pub fn dot<T: Float>(a: &[Complex<T>], b: &[Complex<T>]) -> Complex<T> {
+    assert_eq!(
+        a.len(),
+        b.len(),
+        "dot product of slices with different lengths"
+    );
+    a.iter().zip(b.iter()).map(|(x, y)| x.conj() * *y).sum()
+}
+
+/// Apply a function to every element of a slice in place.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// use xcomplex::slice::map_in_place;
+/// let original = vec![Complex::new(1.0, 2.0), Complex::new(-3.0, 0.5), Complex::new(0.0, -4.0)];
+/// let mut data = original.clone();
+/// map_in_place(&mut data, |z| z.conj());
+/// for (z, o) in data.iter().zip(original.iter()) {
+///     assert_eq!(*z, o.conj());
+/// }
+/// ```
+/// # Panics
+/// This function panics only if `f` panics.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+pub fn map_in_place<T: Float>(data: &mut [Complex<T>], f: impl Fn(Complex<T>) -> Complex<T>) {
+    for z in data.iter_mut() {
+        *z = f(*z);
+    }
+}
+
+/// Apply a function to every element of a slice in parallel and collect the results.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// use xcomplex::slice::par_map;
+/// let data: Vec<Complex> = (0..10_000)
+///     .map(|k| Complex::new(k as f64 / 5000.0 - 1.0, k as f64 / 1000.0))
+///     .collect();
+/// let parallel = par_map(&data, |z| z.exp());
+/// assert_eq!(parallel.len(), data.len());
+/// for (p, z) in parallel.iter().zip(data.iter()) {
+///     assert_eq!(*p, z.exp());
+/// }
+/// ```
+/// # Panics
+/// This function panics only if `f` panics.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// This function requires the `rayon` feature and runs on rayon's global thread
+/// pool. The output keeps the order of the input.
+#[cfg(feature = "rayon")]
+pub fn par_map<T: Float + Send + Sync>(
+    data: &[Complex<T>],
+    f: impl Fn(Complex<T>) -> Complex<T> + Sync,
+) -> Vec<Complex<T>> {
+    data.par_iter().map(|z| f(*z)).collect()
+}
+
+/// Return the component-wise minimum and maximum corners of the bounding box of the points.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// use xcomplex::slice::bounds;
+/// let points = [
+///     Complex::new(1.0, -2.0),
+///     Complex::new(-3.0, 0.5),
+///     Complex::new(2.5, 4.0),
+///     Complex::new(0.0, -1.0),
+/// ];
+/// let (min, max) = bounds(&points).unwrap();
+/// assert_eq!(min, Complex::new(-3.0, -2.0));
+/// assert_eq!(max, Complex::new(2.5, 4.0));
+/// assert_eq!(bounds::<f64>(&[]), None);
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// Comparisons with NaN are false, so NaN components only end up in a corner
+/// when they come from the first point.
+pub fn bounds<T: Float>(points: &[Complex<T>]) -> Option<(Complex<T>, Complex<T>)> {
+    let first = *points.first()?;
+    Some(
+        points[1..]
+            .iter()
+            .fold((first, first), |(mut min, mut max), z| {
+                if z.re < min.re {
+                    min.re = z.re;
+                }
+                if z.im < min.im {
+                    min.im = z.im;
+                }
+                if z.re > max.re {
+                    max.re = z.re;
+                }
+                if z.im > max.im {
+                    max.im = z.im;
+                }
+                (min, max)
+            }),
+    )
+}
+
+/// Scale a slice in place so that its Euclidean norm is one.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// use xcomplex::slice::{dot, normalize};
+/// let mut state = [
+///     Complex::new(1.0, 2.0),
+///     Complex::new(0.0, -1.0),
+///     Complex::new(3.0, 0.5),
+///     Complex::new(-2.0, 2.0),
+/// ];
+/// normalize(&mut state);
+/// let total: f64 = state.iter().map(|z| z.norm_sqr()).sum();
+/// assert!((total - 1.0).abs() < 1e-12);
+/// assert!(dot(&state, &state).approx_eq(&Complex::new(1.0, 0.0), 1e-12));
+/// let mut zeros = [Complex::new(0.0, 0.0); 3];
+/// normalize(&mut zeros);
+/// assert_eq!(zeros, [Complex::new(0.0, 0.0); 3]);
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// Every element is divided by `sqrt(sum(norm_sqr))`. A slice whose norm is zero
+/// is left unchanged.
+pub fn normalize<T: Float>(data: &mut [Complex<T>]) {
+    let norm = data
+        .iter()
+        .fold(T::ZERO, |acc, z| acc + z.norm_sqr())
+        .sqrt();
+    if norm == T::ZERO {
+        return;
+    }
+    map_in_place(data, |z| z / norm);
+}
+
+/// Return the sum of a slice using compensated summation.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// use xcomplex::slice::kahan_sum;
+/// let block = [
+///     Complex::new(1.0, 0.5),
+///     Complex::new(1e100, -1e100),
+///     Complex::new(1.0, 0.5),
+///     Complex::new(-1e100, 1e100),
+/// ];
+/// let data: Vec<Complex> = block.iter().cycle().take(4000).copied().collect();
+/// let exact = Complex::new(2000.0, 1000.0);
+/// let naive: Complex = data.iter().sum();
+/// let compensated = kahan_sum(&data);
+/// assert_eq!(compensated, exact);
+/// assert!((compensated - exact).norm() < (naive - exact).norm());
+/// let tenths = vec![Complex::new(0.1, -0.1); 100_000];
+/// let error = (kahan_sum(&tenths) - Complex::new(10_000.0, -10_000.0)).norm();
+/// let naive_error = (tenths.iter().sum::<Complex>() - Complex::new(10_000.0, -10_000.0)).norm();
+/// assert!(error < naive_error);
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// This function applies the Kahan-Babuska (Neumaier) algorithm to the real and
+/// imaginary parts independently, so the rounding error does not grow with the
+/// length of the slice.
+pub fn kahan_sum<T: Float>(data: &[Complex<T>]) -> Complex<T> {
+    let re = neumaier(data.iter().map(|z| z.re));
+    let im = neumaier(data.iter().map(|z| z.im));
+    Complex::new(re, im)
+}
+
+fn neumaier<T: Float>(values: impl Iterator<Item = T>) -> T {
+    let mut sum = T::ZERO;
+    let mut compensation = T::ZERO;
+    for x in values {
+        let t = sum + x;
+        if sum.abs() >= x.abs() {
+            compensation = compensation + ((sum - t) + x);
+        } else {
+            compensation = compensation + ((x - t) + sum);
+        }
+        sum = t;
+    }
+    sum + compensation
+}
+
+/// Pack real samples into complex numbers with zero imaginary parts.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// use xcomplex::slice::from_real;
+/// let packed = from_real(&[1.0, -2.5, 3.0]);
+/// assert_eq!(packed, vec![Complex::new(1.0, 0.0), Complex::new(-2.5, 0.0), Complex::new(3.0, 0.0)]);
+/// assert!(packed.iter().all(|z| z.im == 0.0));
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+pub fn from_real<T: Float>(data: &[T]) -> Vec<Complex<T>> {
+    data.iter().map(|&x| Complex::from(x)).collect()
+}
+
+/// Return the real parts of a slice of complex numbers.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// use xcomplex::slice::{from_real, to_real};
+/// let x = [0.5, 1e-3, -7.0, 2.0];
+/// assert_eq!(to_real(&from_real(&x)), x);
+/// assert_eq!(to_real(&[Complex::new(1.0, 2.0)]), vec![1.0]);
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// The imaginary parts are discarded.
+pub fn to_real<T: Float>(data: &[Complex<T>]) -> Vec<T> {
+    data.iter().map(|z| z.re).collect()
+}
+
+/// Return a view of a slice of complex numbers as interleaved `re, im` components.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// use xcomplex::slice::as_float_slice;
+/// let data = [Complex::new(1.0, 2.0), Complex::new(3.0, 4.0), Complex::new(5.0, 6.0)];
+/// let flat = as_float_slice(&data);
+/// assert_eq!(flat.len(), 2 * data.len());
+/// assert_eq!(flat, &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+/// let single: [Complex<f32>; 1] = [Complex::new(7.0, 8.0)];
+/// assert_eq!(as_float_slice(&single), &[7.0_f32, 8.0]);
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// `Complex` is `#[repr(C)]` and laid out like `[T; 2]`, so the slice is
+/// reinterpreted in place without copying. The function is generic, so an
+/// `f32` slice is viewed as `&[f32]`.
+pub fn as_float_slice<T: Float>(data: &[Complex<T>]) -> &[T] {
+    // SAFETY: `Complex<T>` is `#[repr(C)]` and consists of exactly two `T`s, so
+    // `data.len()` complex numbers are `2 * data.len()` contiguous `T`s.
+    unsafe { core::slice::from_raw_parts(data.as_ptr() as *const T, 2 * data.len()) }
+}
+
+/// Return a mutable view of a slice of complex numbers as interleaved `re, im` components.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// use xcomplex::slice::as_float_slice_mut;
+/// let mut data = [Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)];
+/// let flat = as_float_slice_mut(&mut data);
+/// assert_eq!(flat.len(), 4);
+/// flat[1] = -2.0;
+/// flat[2] *= 10.0;
+/// assert_eq!(data, [Complex::new(1.0, -2.0), Complex::new(30.0, 4.0)]);
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// See `as_float_slice` for the layout guarantee.
+pub fn as_float_slice_mut<T: Float>(data: &mut [Complex<T>]) -> &mut [T] {
+    // SAFETY: see `as_float_slice`; the exclusive borrow of `data` is carried over.
+    unsafe { core::slice::from_raw_parts_mut(data.as_mut_ptr() as *mut T, 2 * data.len()) }
+}
+
+/// Return a view of a slice of `f64` complex numbers as interleaved `re, im` components.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// use xcomplex::slice::as_f64_slice;
+/// let data = [Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)];
+/// assert_eq!(as_f64_slice(&data), &[1.0, 2.0, 3.0, 4.0]);
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// This function is `as_float_slice` restricted to `f64` components.
+pub fn as_f64_slice(data: &[Complex<f64>]) -> &[f64] {
+    as_float_slice(data)
+}
+
+/// Return a mutable view of a slice of `f64` complex numbers as interleaved `re, im` components.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// use xcomplex::slice::as_f64_slice_mut;
+/// let mut data = [Complex::new(1.0, 2.0), Complex::new(3.0, 4.0)];
+/// as_f64_slice_mut(&mut data)[3] = -4.0;
+/// assert_eq!(data[1], Complex::new(3.0, -4.0));
+/// ```
+/// # Panics
+/// This function does not panic.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// This function is `as_float_slice_mut` restricted to `f64` components.
+pub fn as_f64_slice_mut(data: &mut [Complex<f64>]) -> &mut [f64] {
+    as_float_slice_mut(data)
+}
@@ -0,0 +1,71 @@
+//! solve
+//!
+//! This module contains iterative root finders for user supplied complex functions.
+//!
+//! # Examples
+//!
+//! ```
+//! use xcomplex::number::Complex;
+//! use xcomplex::solve::newton;
+//!
+//! // the cube roots of unity are reached from different starting points
+//! let root = newton(|z| z * z * z - 1.0, |z| z * z * 3.0, Complex::new(-1.0, 1.0), 1e-12, 50);
+//! let expected = Complex::from_polar(1.0, 2.0 * std::f64::consts::PI / 3.0);
+//! assert!(root.unwrap().approx_eq(&expected, 1e-12));
+//! ```
+//!
+//! # References
+//!
+//! * [Newton's method](https://en.wikipedia.org/wiki/Newton%27s_method)
+
+use crate::float::Float;
+use crate::number::Complex;
+
+/// Find a root of `f` with Newton's method, given its derivative `df`.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// use xcomplex::solve::newton;
+/// let f = |z: Complex| z * z + 1.0;
+/// let df = |z: Complex| z * 2.0;
+/// let root = newton(f, df, Complex::new(0.1, 0.9), 1e-12, 50).unwrap();
+/// assert!(root.approx_eq(&Complex::new(0.0, 1.0), 1e-12));
+/// assert!(f(root).norm() < 1e-12);
+/// // starting on the real axis never leaves it, so there is no root to find
+/// assert_eq!(newton(f, df, Complex::new(0.5, 0.0), 1e-12, 50), None);
+/// // a vanishing derivative stops the iteration
+/// assert_eq!(newton(f, df, Complex::new(0.0, 0.0), 1e-12, 50), None);
+/// ```
+/// # Panics
+/// This function panics only if `f` or `df` panics.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// This function iterates `z - f(z) / df(z)` from `start` and returns the iterate
+/// once the norm of a step falls below `tol`. It returns `None` if that does not
+/// happen within `max_iter` steps or if a step is not finite, which is the case
+/// when the derivative vanishes.
+pub fn newton<T: Float>(
+    f: impl Fn(Complex<T>) -> Complex<T>,
+    df: impl Fn(Complex<T>) -> Complex<T>,
+    start: Complex<T>,
+    tol: T,
+    max_iter: usize,
+) -> Option<Complex<T>> {
+    let mut z = start;
+    for _ in 0..max_iter {
+        let step = f(z) / df(z);
+        if !step.is_finite() {
+            return None;
+        }
+        z -= step;
+        if step.norm() < tol {
+            return Some(z);
+        }
+    }
+    None
+}
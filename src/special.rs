@@ -0,0 +1,248 @@
+//! special functions
+//!
+//! This module contains special functions of complex arguments, such as `erf`
+//! and `gamma`, and the building blocks used to evaluate them.
+//!
+//! # Examples
+//!
+//! ```
+//! use xcomplex::number::Complex;
+//! use xcomplex::special::continued_fraction;
+//!
+//! // sqrt(2) = 1 + 1 / (2 + 1 / (2 + ...))
+//! let one = Complex::new(1.0, 0.0);
+//! let two = Complex::new(2.0, 0.0);
+//! let mut b = vec![two; 30];
+//! b[0] = one;
+//! let s = continued_fraction(&[one; 29], &b);
+//! assert!(s.approx_eq(&Complex::new(2.0_f64.sqrt(), 0.0), 1e-12));
+//! ```
+//!
+//! # References
+//!
+//! * [Generalized continued fraction](https://en.wikipedia.org/wiki/Generalized_continued_fraction)
+//! * [Lentz's algorithm](https://en.wikipedia.org/wiki/Lentz%27s_algorithm)
+//! * [Error function](https://en.wikipedia.org/wiki/Error_function)
+//! * [Lanczos approximation](https://en.wikipedia.org/wiki/Lanczos_approximation)
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::float::Float;
+use crate::number::Complex;
+
+/// Evaluate the continued fraction `b[0] + a[0] / (b[1] + a[1] / (b[2] + ...))`.
+/// # Examples
+/// ```
+/// use xcomplex::number::Complex;
+/// use xcomplex::special::continued_fraction;
+/// // the golden ratio is 1 + 1 / (1 + 1 / (1 + ...))
+/// let one = Complex::new(1.0, 0.0);
+/// let phi = continued_fraction(&[one; 40], &[one; 41]);
+/// assert!(phi.approx_eq(&Complex::new((1.0 + 5.0_f64.sqrt()) / 2.0, 0.0), 1e-12));
+/// // a zero leading term and a zero intermediate denominator are nudged, not divided by
+/// let z = Complex::new(0.0, 0.0);
+/// let i = Complex::new(0.0, 1.0);
+/// let v = continued_fraction(&[one, i], &[z, i, one]);
+/// // 0 + 1 / (i + i / 1) = 1 / 2i
+/// assert!(v.approx_eq(&Complex::new(0.0, -0.5), 1e-12));
+/// assert_eq!(continued_fraction(&[], &[i]), i);
+/// ```
+/// ```should_panic
+/// use xcomplex::number::Complex;
+/// use xcomplex::special::continued_fraction;
+/// let one = Complex::new(1.0, 0.0);
+/// continued_fraction(&[one; 3], &[one; 3]);
+/// ```
+/// # Panics
+/// This function panics if `b` does not have exactly one more term than `a`.
+/// # Safety
+/// This function is safe.
+/// # Aborts
+/// This function does not abort.
+/// # Undefined Behavior
+/// This function does not cause undefined behavior.
+/// # Implementation Details
+/// This function uses the modified Lentz algorithm, evaluating the fraction front
+/// to back as a product of convergent ratios. Whenever a leading term or an
+/// intermediate denominator vanishes it is replaced by `1e-30`, as Lentz
+/// prescribes, instead of dividing by zero.
+pub fn continued_fraction<T: Float>(a: &[Complex<T>], b: &[Complex<T>]) -> Complex<T> {
+    assert_eq!(
+        a.len() + 1,
+        b.len(),
+        "continued fraction needs one more b term than a terms"
+    );
+    let tiny = T::from_f64(1e-30);
+    let nudge = |z: Complex<T>| {
+        if z.norm() < tiny {
+            Complex::new(tiny, T::ZERO)
+        } else {
+            z
+        }
+    };
+    let mut f = nudge(b[0]);
+    let mut c = f;
+    let mut d = Complex::ZERO;
+    for (aj, bj) in a.iter().zip(b[1..].iter()) {
+        d = nudge(*bj + *aj * d).inv();
+        c = nudge(*bj + *aj / c);
+        f *= c * d;
+    }
+    f
+}
+
+const ERF_SERIES_RADIUS: f64 = 3.0;
+const ERF_SERIES_MAX_RE: f64 = 2.0;
+const ERF_SERIES_TERMS: usize = 2000;
+const ERFC_FRACTION_TERMS: usize = 128;
+const LANCZOS_G: f64 = 7.0;
+const LANCZOS_COEFFICIENTS: [f64; 9] = [
+    0.9999999999998099,
+    676.5203681218851,
+    -1259.1392167224028,
+    771.3234287776531,
+    -176.6150291621406,
+    12.507343278686905,
+    -0.13857109526572012,
+    9.984369578019572e-6,
+    1.5056327351493116e-7,
+];
+
+impl<T: Float> Complex<T> {
+    /// Return the error function of the complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let e = Complex::new(1.0, 0.0).erf();
+    /// assert!(e.approx_eq(&Complex::new(0.8427007929497149, 0.0), 1e-15));
+    /// assert_eq!(e.im, 0.0);
+    /// assert!(Complex::new(1.0, 1.0).erf().approx_eq(&Complex::new(1.3161512816979476, 0.19045346923783471), 1e-12));
+    /// assert!(Complex::new(-2.0, 0.0).erf().approx_eq(&Complex::new(-0.9953222650189527, 0.0), 1e-15));
+    /// assert!(Complex::new(3.5, 0.0).erf().approx_eq(&Complex::new(0.999999256901628, 0.0), 1e-15));
+    /// assert!(Complex::new(-3.5, 0.0).erf().approx_eq(&Complex::new(-0.999999256901628, 0.0), 1e-15));
+    /// let e: Complex = Complex::new(0.0, 3.5).erf();
+    /// assert_eq!(e.re, 0.0);
+    /// assert!((e.im - 35282.2877151717).abs() <= 1e-12 * 35282.2877151717);
+    /// assert!(Complex::new(0.01, 3.5).erf().approx_eq(&Complex::new(2356.09753023473, 35199.7919887236), 1e-9));
+    /// let z = Complex::new(2.0, -2.5);
+    /// assert!(z.conj().erf().approx_eq(&z.erf().conj(), 1e-12));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// For `norm() < 3` or `|re| < 2` this function sums the Maclaurin series, which
+    /// keeps purely imaginary arguments purely imaginary. Elsewhere it evaluates the
+    /// Laplace continued fraction for `erfc` in the right half-plane with
+    /// `continued_fraction` and uses `erf(-z) = -erf(z)` for the left one; the
+    /// fraction converges too slowly close to the imaginary axis to be used there.
+    pub fn erf(&self) -> Self {
+        if self.norm() < T::from_f64(ERF_SERIES_RADIUS)
+            || self.re.abs() < T::from_f64(ERF_SERIES_MAX_RE)
+        {
+            return erf_series(*self);
+        }
+        if self.re < T::ZERO {
+            return erfc_fraction(-*self) - T::ONE;
+        }
+        -erfc_fraction(*self) + T::ONE
+    }
+
+    /// Return the gamma function of the complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// assert!(Complex::new(5.0, 0.0).gamma().approx_eq(&Complex::new(24.0, 0.0), 1e-10));
+    /// let sqrt_pi = std::f64::consts::PI.sqrt();
+    /// assert!(Complex::new(0.5, 0.0).gamma().approx_eq(&Complex::new(sqrt_pi, 0.0), 1e-12));
+    /// assert!(Complex::new(-0.5, 0.0).gamma().approx_eq(&Complex::new(-2.0 * sqrt_pi, 0.0), 1e-12));
+    /// let mut factorial = 1.0;
+    /// for n in 1..10 {
+    ///     assert!((Complex::new(n as f64, 0.0).gamma().re - factorial).abs() <= 1e-12 * factorial);
+    ///     factorial *= n as f64;
+    /// }
+    /// let z = Complex::new(1.5, 2.0);
+    /// assert!((z + 1.0).gamma().approx_eq(&(z * z.gamma()), 1e-12));
+    /// // the poles give NaN instead of a huge finite value
+    /// assert!(Complex::new(0.0, 0.0).gamma().is_nan());
+    /// assert!(Complex::new(-1.0, 0.0).gamma().is_nan());
+    /// assert!(Complex::new(-1.0, 1e-3).gamma().is_finite());
+    /// // far left of the origin the result underflows to a zero of the right sign
+    /// let g: Complex = Complex::new(-200.5, 0.0).gamma();
+    /// assert!(g.re == 0.0 && g.re.is_sign_negative());
+    /// assert_eq!(g.im, 0.0);
+    /// let g: Complex = Complex::new(-201.5, 0.0).gamma();
+    /// assert!(g.re == 0.0 && g.re.is_sign_positive());
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function uses the Lanczos approximation with `g = 7` and nine
+    /// coefficients, which is accurate to about 15 significant digits. For
+    /// `re < 0.5` it applies the reflection formula
+    /// `gamma(z) = PI / (sin(PI z) gamma(1 - z))`. At the poles, zero and the
+    /// negative integers on the real axis, both components of the result are NaN;
+    /// `sin(PI z)` is not exactly zero there, so they are checked for explicitly.
+    /// When `gamma(1 - z)` overflows in the reflection, the result is a zero with
+    /// the component signs of `1 / sin(PI z)`.
+    pub fn gamma(&self) -> Self {
+        if self.im == T::ZERO && self.re <= T::ZERO && self.re.floor() == self.re {
+            return Complex::new(T::NAN, T::NAN);
+        }
+        if self.re < T::from_f64(0.5) {
+            let sin = (*self * T::PI).sin();
+            let reflected = (-*self + T::ONE).gamma();
+            if !reflected.is_finite() {
+                // the quotient underflows; keep the signs of `1 / sin(PI z)`
+                return Complex::new(T::ZERO.copysign(sin.re), T::ZERO.copysign(-sin.im));
+            }
+            let pi = Complex::new(T::PI, T::ZERO);
+            return pi / (sin * reflected);
+        }
+        let z = *self - T::ONE;
+        let x = LANCZOS_COEFFICIENTS[1..].iter().enumerate().fold(
+            Complex::new(T::from_f64(LANCZOS_COEFFICIENTS[0]), T::ZERO),
+            |x, (i, p)| x + (z + T::from_f64(i as f64 + 1.0)).inv() * T::from_f64(*p),
+        );
+        let t = z + T::from_f64(LANCZOS_G + 0.5);
+        let e = ((z + T::from_f64(0.5)) * t.ln() - t).exp();
+        e * x * (T::TWO * T::PI).sqrt()
+    }
+}
+
+fn erf_series<T: Float>(z: Complex<T>) -> Complex<T> {
+    let z2 = z * z;
+    let tolerance = T::from_f64(1e-17);
+    let mut term = z;
+    let mut sum = z;
+    for n in 1..ERF_SERIES_TERMS {
+        let n = T::from_f64(n as f64);
+        term = -term * z2 / n;
+        let step = term / (T::TWO * n + T::ONE);
+        sum += step;
+        if step.norm() <= tolerance * sum.norm() {
+            break;
+        }
+    }
+    sum * (T::TWO / T::PI.sqrt())
+}
+
+fn erfc_fraction<T: Float>(z: Complex<T>) -> Complex<T> {
+    let a: Vec<Complex<T>> = (1..ERFC_FRACTION_TERMS)
+        .map(|k| Complex::new(T::from_f64(k as f64) / T::TWO, T::ZERO))
+        .collect();
+    let b = vec![z; ERFC_FRACTION_TERMS];
+    (-(z * z)).exp() / (continued_fraction(&a, &b) * T::PI.sqrt())
+}
@@ -0,0 +1,172 @@
+//! trigonometry
+//!
+//! This module contains the reciprocal circular functions `cot`, `sec` and `csc`
+//! and their inverses, implemented as methods on `Complex`.
+//!
+//! # Examples
+//!
+//! ```
+//! use xcomplex::number::Complex;
+//!
+//! let z = Complex::new(0.5, 0.3);
+//! assert!((z.sec() * z.cos()).approx_eq(&Complex::new(1.0, 0.0), 1e-12));
+//! assert!(z.sec().asec().approx_eq(&z, 1e-12));
+//! ```
+//!
+//! # References
+//!
+//! * [Trigonometric functions](https://en.wikipedia.org/wiki/Trigonometric_functions)
+//! * [Inverse trigonometric functions](https://en.wikipedia.org/wiki/Inverse_trigonometric_functions)
+
+use crate::float::Float;
+use crate::number::Complex;
+
+impl<T: Float> Complex<T> {
+    /// Return the cotangent of the complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert!((c.cot() * c.tan()).approx_eq(&Complex::new(1.0, 0.0), 1e-12));
+    /// assert!(c.cot().approx_eq(&(c.cos() / c.sin()), 1e-15));
+    /// assert_eq!(Complex::new(1.0, 1000.0).cot(), Complex::new(0.0, -1.0));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function computes `1 / tan(z)`, so it stays bounded for large
+    /// imaginary parts where `cos / sin` would overflow.
+    pub fn cot(&self) -> Self {
+        self.tan().inv()
+    }
+
+    /// Return the secant of the complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert!((c.sec() * c.cos()).approx_eq(&Complex::new(1.0, 0.0), 1e-12));
+    /// assert_eq!(Complex::new(0.0, 0.0).sec(), Complex::new(1.0, 0.0));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn sec(&self) -> Self {
+        self.cos().inv()
+    }
+
+    /// Return the cosecant of the complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let c = Complex::new(1.0, 2.0);
+    /// assert!((c.csc() * c.sin()).approx_eq(&Complex::new(1.0, 0.0), 1e-12));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    pub fn csc(&self) -> Self {
+        self.sin().inv()
+    }
+
+    /// Return the principal arccotangent of the complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let z = Complex::new(0.5, 0.3);
+    /// assert!(z.cot().acot().approx_eq(&z, 1e-12));
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function computes `atan(1 / z)`, reusing the logarithmic form of `atan`.
+    pub fn acot(&self) -> Self {
+        self.inv().atan()
+    }
+
+    /// Return the principal arcsecant of the complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let z = Complex::new(0.5, 0.3);
+    /// assert!(z.sec().asec().approx_eq(&z, 1e-12));
+    /// // small arguments have large reciprocals, checked against C99 `cacos(1 / z)`
+    /// let cases = [
+    ///     (Complex::new(1e-10, 1e-10), Complex::new(0.7853981633974483, 23.372424520220427)),
+    ///     (Complex::new(-1e-10, 1e-10), Complex::new(2.356194490192345, 23.372424520220427)),
+    ///     (Complex::new(-1e-10, -1e-10), Complex::new(2.356194490192345, -23.372424520220427)),
+    ///     (Complex::new(1e-10, -1e-10), Complex::new(0.7853981633974483, -23.372424520220427)),
+    ///     (Complex::new(-0.3, 0.2), Complex::new(2.5225182232553616, 1.70197839016291)),
+    /// ];
+    /// for (z, w) in cases {
+    ///     assert!(z.asec().approx_eq(&w, 1e-14));
+    /// }
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function computes `acos(1 / z)`, reusing the first-quadrant evaluation of `acos`.
+    pub fn asec(&self) -> Self {
+        self.inv().acos()
+    }
+
+    /// Return the principal arccosecant of the complex number.
+    /// # Examples
+    /// ```
+    /// use xcomplex::number::Complex;
+    /// let z = Complex::new(0.5, 0.3);
+    /// assert!(z.csc().acsc().approx_eq(&z, 1e-12));
+    /// // small arguments have large reciprocals, checked against C99 `casin(1 / z)`
+    /// let cases = [
+    ///     (Complex::new(1e-10, 1e-10), Complex::new(0.7853981633974483, -23.372424520220427)),
+    ///     (Complex::new(-1e-10, 1e-10), Complex::new(-0.7853981633974483, -23.372424520220427)),
+    ///     (Complex::new(-1e-10, -1e-10), Complex::new(-0.7853981633974483, 23.372424520220427)),
+    ///     (Complex::new(1e-10, -1e-10), Complex::new(0.7853981633974483, 23.372424520220427)),
+    ///     (Complex::new(-0.3, 0.2), Complex::new(-0.9517218964604648, -1.70197839016291)),
+    /// ];
+    /// for (z, w) in cases {
+    ///     assert!(z.acsc().approx_eq(&w, 1e-14));
+    /// }
+    /// ```
+    /// # Panics
+    /// This function does not panic.
+    /// # Safety
+    /// This function is safe.
+    /// # Aborts
+    /// This function does not abort.
+    /// # Undefined Behavior
+    /// This function does not cause undefined behavior.
+    /// # Implementation Details
+    /// This function computes `asin(1 / z)`, reusing the first-quadrant evaluation of `asin`.
+    pub fn acsc(&self) -> Self {
+        self.inv().asin()
+    }
+}